@@ -0,0 +1,114 @@
+//! A global allocator that counts the allocations of each thread, to see the memory cost of a
+//! solver next to its run time.
+//!
+//! Binaries opt in by installing it with `#[global_allocator]`, and it only starts counting once
+//! [`enable`] is called. Until then it costs one atomic load per allocation.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+struct Counters {
+    /// Signed, since memory can be freed by another thread than the one that allocated it.
+    current: Cell<isize>,
+    peak: Cell<isize>,
+    count: Cell<u64>,
+    bytes: Cell<u64>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters { current: Cell::new(0), peak: Cell::new(0), count: Cell::new(0), bytes: Cell::new(0) }
+    };
+}
+
+fn record(delta: isize, allocated: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // Fails while the thread is shutting down, those allocations go uncounted
+    let _ = COUNTERS.try_with(|c| {
+        let current = c.current.get() + delta;
+        c.current.set(current);
+        c.peak.set(c.peak.get().max(current));
+        if allocated > 0 {
+            c.count.set(c.count.get() + 1);
+            c.bytes.set(c.bytes.get() + allocated as u64);
+        }
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size() as isize, layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record(-(layout.size() as isize), 0);
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record(layout.size() as isize, layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize, new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting. Only has an effect in binaries that installed [`CountingAllocator`].
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Heap use of one phase on one thread.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AllocStats {
+    /// Most memory in use at once, beyond what was in use when the phase started.
+    pub peak: usize,
+    /// Number of allocations, counting reallocations.
+    pub count: u64,
+    /// Bytes requested by those allocations.
+    pub bytes: u64,
+}
+
+/// Runs `f`, returning what it allocated on this thread. All zero if counting isn't enabled.
+///
+/// Calls can be nested, the outer one's peak covers the inner one's.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let (outer_peak, start) = COUNTERS.with(|c| {
+        (c.peak.replace(c.current.get()), (c.current.get(), c.count.get(), c.bytes.get()))
+    });
+    let result = f();
+    let stats = COUNTERS.with(|c| {
+        let peak = c.peak.get();
+        c.peak.set(peak.max(outer_peak));
+        AllocStats {
+            peak: (peak - start.0).max(0) as usize,
+            count: c.count.get() - start.1,
+            bytes: c.bytes.get() - start.2,
+        }
+    });
+    (result, stats)
+}
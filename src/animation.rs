@@ -0,0 +1,127 @@
+use std::io::{self, Write};
+use crate::automaton::Grid;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Crop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Crop {
+    /// Parses `x,y,width,height`.
+    pub fn parse(s: &str) -> Option<Crop> {
+        let mut it = s.split(',').map(|s| s.parse::<usize>());
+        let crop = Crop {
+            x: it.next()?.ok()?,
+            y: it.next()?.ok()?,
+            width: it.next()?.ok()?,
+            height: it.next()?.ok()?,
+        };
+        it.next().is_none().then_some(crop)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AnimationOptions {
+    /// Generations per frame. Cells removed in any of them are highlighted together.
+    pub frame_step: usize,
+    pub frame_millis: u32,
+    pub cell_pixels: u32,
+    pub crop: Option<Crop>,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        AnimationOptions {
+            frame_step: 1,
+            frame_millis: 200,
+            cell_pixels: 4,
+            crop: None,
+        }
+    }
+}
+
+const EMPTY: i64 = -1;
+const NEVER_REMOVED: i64 = -2;
+
+/// Writes a self-contained HTML page animating cells being removed over generations.
+///
+/// `removed_in` holds the generation each occupied cell was removed in, if any. Each frame shows
+/// the cells removed during it highlighted, and they disappear on the following frame.
+///
+/// Fails with [`io::ErrorKind::InvalidInput`] if the options don't fit the grid.
+pub fn write_removal_html(
+    out: &mut impl Write,
+    occupied: &Grid<bool>,
+    removed_in: &Grid<Option<usize>>,
+    options: &AnimationOptions,
+) -> io::Result<()> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, message);
+    if options.frame_step == 0 {
+        return Err(invalid("Frame step must be at least 1"));
+    }
+    let crop = options.crop.unwrap_or(Crop { x: 0, y: 0, width: occupied.width(), height: occupied.height() });
+    let fits = |start: usize, len: usize, max: usize| start.checked_add(len).is_some_and(|end| end <= max);
+    if !fits(crop.x, crop.width, occupied.width()) || !fits(crop.y, crop.height, occupied.height()) {
+        return Err(invalid("Crop exceeds grid"));
+    }
+    let canvas_size = |cells: usize| u32::try_from(cells).ok().and_then(|cells| cells.checked_mul(options.cell_pixels));
+    let (Some(canvas_width), Some(canvas_height)) = (canvas_size(crop.width), canvas_size(crop.height)) else {
+        return Err(invalid("Canvas too large"));
+    };
+
+    let mut cells = Vec::with_capacity(crop.width * crop.height);
+    for y in crop.y..crop.y + crop.height {
+        for x in crop.x..crop.x + crop.width {
+            cells.push(match (occupied[[x, y]], removed_in[[x, y]]) {
+                (false, _) => EMPTY,
+                (true, None) => NEVER_REMOVED,
+                (true, Some(g)) => g as i64,
+            });
+        }
+    }
+
+    let generations = removed_in.rows().flatten().flatten().max().map_or(0, |g| g + 1);
+    // One frame per step, plus the final state with nothing left to remove
+    let mut frame_starts: Vec<usize> = (0..generations).step_by(options.frame_step).collect();
+    frame_starts.push(generations);
+
+    let cells_s = cells.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(",");
+    let frames_s = frame_starts.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(",");
+    let px = options.cell_pixels;
+
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html><head><meta charset=\"utf-8\"><title>Removal animation</title></head>")?;
+    writeln!(out, "<body style=\"background:#0f0f23;color:#ccc;font-family:monospace\">")?;
+    writeln!(out, "<div id=\"label\"></div>")?;
+    writeln!(out, "<canvas id=\"c\" width=\"{canvas_width}\" height=\"{canvas_height}\"></canvas>")?;
+    writeln!(out, "<script>")?;
+    writeln!(out, "const W = {}, H = {}, PX = {px}, MS = {};", crop.width, crop.height, options.frame_millis)?;
+    writeln!(out, "const cells = [{cells_s}];")?;
+    writeln!(out, "const frames = [{frames_s}];")?;
+    out.write_all(br##"const ctx = document.getElementById("c").getContext("2d");
+const label = document.getElementById("label");
+let frame = 0;
+function draw() {
+  const start = frames[frame];
+  const end = frame + 1 < frames.length ? frames[frame + 1] : start;
+  ctx.fillStyle = "#0f0f23";
+  ctx.fillRect(0, 0, W * PX, H * PX);
+  for (let i = 0; i < cells.length; i++) {
+    const g = cells[i];
+    if (g == -1 || (g >= 0 && g < start)) continue;
+    ctx.fillStyle = g >= 0 && g < end ? "#ff4040" : "#ffff66";
+    ctx.fillRect((i % W) * PX, Math.floor(i / W) * PX, PX, PX);
+  }
+  label.textContent = "generation " + start + " (frame " + (frame + 1) + "/" + frames.length + ")";
+  frame = (frame + 1) % frames.length;
+}
+draw();
+setInterval(draw, MS);
+</script>
+</body></html>
+"##)?;
+    Ok(())
+}
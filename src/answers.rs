@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Where answer attempts for the local inputs are kept, one `<day> <part> <verdict> <answer>` per
+/// line. Ignored by git along with the inputs they belong to.
+pub const DEFAULT_PATH: &str = "local/answers.txt";
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Rejected without a hint in which direction.
+    Wrong,
+}
+
+impl Verdict {
+    pub const ALL: [Verdict; 4] = [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong];
+
+    /// The keyword used for the verdict in the answers file.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    pub fn from_keyword(s: &str) -> Option<Verdict> {
+        Verdict::ALL.into_iter().find(|v| v.keyword() == s)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// How a freshly computed answer compares to what's known about the day and part.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Assessment {
+    /// Matches the answer recorded as correct.
+    Verified,
+    /// A correct answer is recorded and this isn't it.
+    Wrong { expected: String },
+    /// The same answer was already submitted and rejected.
+    KnownWrong(Verdict),
+    /// Not above an answer that was too low.
+    TooLow { bound: String },
+    /// Not below an answer that was too high.
+    TooHigh { bound: String },
+    /// Nothing recorded rules it in or out.
+    Unchecked,
+}
+
+impl Assessment {
+    /// `None` if it's not known either way.
+    pub fn is_correct(&self) -> Option<bool> {
+        match self {
+            Assessment::Verified => Some(true),
+            Assessment::Unchecked => None,
+            _ => Some(false),
+        }
+    }
+}
+
+impl fmt::Display for Assessment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Assessment::Verified => write!(f, "verified"),
+            Assessment::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Assessment::KnownWrong(verdict) => write!(f, "already submitted, {verdict}"),
+            Assessment::TooLow { bound } => write!(f, "too low, not above {bound}"),
+            Assessment::TooHigh { bound } => write!(f, "too high, not below {bound}"),
+            Assessment::Unchecked => write!(f, "?"),
+        }
+    }
+}
+
+/// Compares two answers as non-negative decimal numbers of any length. `None` if either isn't one.
+fn compare_numbers(a: &str, b: &str) -> Option<Ordering> {
+    fn digits(s: &str) -> Option<&str> {
+        let s = s.trim_start_matches('0');
+        s.bytes().all(|b| b.is_ascii_digit()).then_some(s)
+    }
+    let (a, b) = (digits(a)?, digits(b)?);
+    Some(a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+}
+
+/// Answers submitted so far, keyed by day and part, in the order they were recorded.
+#[derive(Clone, Debug, Default)]
+pub struct RecordedAnswers {
+    attempts: HashMap<(u32, u32), Vec<Attempt>>,
+}
+
+impl RecordedAnswers {
+    /// Parses the answers file. A line without a verdict records a correct answer.
+    pub fn parse(text: &str) -> io::Result<RecordedAnswers> {
+        let mut recorded = RecordedAnswers::default();
+        let lines = text.lines().map(str::trim).enumerate().filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+        for (i, line) in lines {
+            let bad_line = || io::Error::new(io::ErrorKind::InvalidData, format!("Line {}: expected <day> <part> [<verdict>] <answer>, got {line:?}", i + 1));
+            let mut it = line.splitn(3, ' ');
+            let mut number = || it.next().and_then(|s| s.parse().ok()).ok_or_else(bad_line);
+            let key = (number()?, number()?);
+            let rest = it.next().ok_or_else(bad_line)?.trim();
+            let attempt = match rest.split_once(' ').and_then(|(v, answer)| Some((Verdict::from_keyword(v)?, answer))) {
+                Some((verdict, answer)) => Attempt { verdict, answer: answer.trim().to_string() },
+                None => Attempt { verdict: Verdict::Correct, answer: rest.to_string() },
+            };
+            recorded.attempts.entry(key).or_default().push(attempt);
+        }
+        Ok(recorded)
+    }
+
+    /// Loads the answers from `path`. A missing file means nothing has been recorded yet.
+    pub fn load(path: &Path) -> io::Result<RecordedAnswers> {
+        match fs::read_to_string(path) {
+            Ok(text) => RecordedAnswers::parse(&text).map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(RecordedAnswers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Appends an attempt to the answers file at `path`, creating it if needed.
+    pub fn append(path: &Path, day: u32, part: u32, attempt: &Attempt) -> io::Result<()> {
+        assert!(!attempt.answer.is_empty() && !attempt.answer.contains('\n'));
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{day} {part} {} {}", attempt.verdict.keyword(), attempt.answer)
+    }
+
+    pub fn attempts(&self, day: u32, part: u32) -> &[Attempt] {
+        self.attempts.get(&(day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    /// The answer recorded as correct, if any.
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.attempts(day, part).iter().rev()
+            .find(|a| a.verdict == Verdict::Correct)
+            .map(|a| a.answer.as_str())
+    }
+
+    pub fn assess(&self, day: u32, part: u32, answer: &str) -> Assessment {
+        if let Some(expected) = self.get(day, part) {
+            return if answer == expected { Assessment::Verified } else { Assessment::Wrong { expected: expected.to_string() } };
+        }
+        let attempts = self.attempts(day, part);
+        if let Some(attempt) = attempts.iter().find(|a| a.answer == answer) {
+            return Assessment::KnownWrong(attempt.verdict);
+        }
+        // An earlier attempt that `answer` isn't on the right side of
+        let rejected_by = |verdict, right_side| {
+            attempts.iter()
+                .filter(|a| a.verdict == verdict)
+                .find(|a| compare_numbers(answer, &a.answer).is_some_and(|o| o != right_side))
+                .map(|a| a.answer.clone())
+        };
+        if let Some(bound) = rejected_by(Verdict::TooLow, Ordering::Greater) {
+            return Assessment::TooLow { bound };
+        }
+        if let Some(bound) = rejected_by(Verdict::TooHigh, Ordering::Less) {
+            return Assessment::TooHigh { bound };
+        }
+        Assessment::Unchecked
+    }
+}
@@ -0,0 +1,271 @@
+use std::array;
+use std::collections::VecDeque;
+use std::iter;
+use std::mem;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: impl IntoIterator<Item=Vec<T>>) -> Grid<T> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for row in rows {
+            if height == 0 {
+                width = row.len();
+            }
+            assert_eq!(row.len(), width);
+            cells.extend(row);
+            height += 1;
+        }
+        Grid { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    fn index(&self, [x, y]: [usize; 2]) -> usize {
+        assert!(x < self.width && y < self.height);
+        y * self.width + x
+    }
+
+    fn position(&self, i: usize) -> [usize; 2] {
+        [i % self.width, i / self.width]
+    }
+}
+
+impl<T> std::ops::Index<[usize; 2]> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: [usize; 2]) -> &T {
+        &self.cells[self.index(pos)]
+    }
+}
+
+impl<T> std::ops::IndexMut<[usize; 2]> for Grid<T> {
+    fn index_mut(&mut self, pos: [usize; 2]) -> &mut T {
+        let i = self.index(pos);
+        &mut self.cells[i]
+    }
+}
+
+/// Positions returned by [`moore_neighbourhood`].
+pub type MooreNeighbours = iter::Flatten<array::IntoIter<Option<[usize; 2]>, 8>>;
+
+const MOORE_OFFSETS: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// The 8 surrounding cells, clipped to a `width`x`height` grid.
+pub fn moore_neighbourhood(width: usize, height: usize) -> impl Fn([usize; 2]) -> MooreNeighbours {
+    move |[x, y]| {
+        MOORE_OFFSETS.map(|(dx, dy)| {
+            Some([x.checked_add_signed(dx)?, y.checked_add_signed(dy)?]).filter(|&[x, y]| x < width && y < height)
+        }).into_iter().flatten()
+    }
+}
+
+/// When the changes made by the rule take effect.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Update {
+    /// All cells of a generation see the grid as it was at its start, and its changes are applied
+    /// together at the end.
+    #[default]
+    Synchronous,
+    /// Each step is a pass over the grid in row-major order, and changes are applied as soon as
+    /// they're made, so cells later in the pass already see them.
+    InPlace,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct StepStats {
+    /// Number of cells the rule was evaluated on.
+    pub examined: usize,
+    /// Number of cells whose state changed.
+    pub changed: usize,
+}
+
+/// Cells waiting to be examined, in ascending order of index.
+///
+/// Cells are queued while others are examined in ascending order, so the cells queued at a given
+/// offset from the one being examined come in ascending order too. Keeping a queue per offset
+/// keeps the whole in order without sorting, and popping the smallest of a handful of queue heads
+/// costs constant time per cell.
+#[derive(Debug, Default)]
+struct Frontier {
+    queues: Vec<(isize, VecDeque<usize>)>,
+}
+
+impl Frontier {
+    /// `cell` must not be smaller than the cells queued before it at the same offset.
+    fn push(&mut self, offset: isize, cell: usize) {
+        let i = match self.queues.iter().position(|(o, _)| *o == offset) {
+            Some(i) => i,
+            None => {
+                self.queues.push((offset, VecDeque::new()));
+                self.queues.len() - 1
+            }
+        };
+        let queue = &mut self.queues[i].1;
+        debug_assert!(queue.back().is_none_or(|&last| last < cell));
+        queue.push_back(cell);
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        let (_, queue) = self.queues.iter_mut()
+            .filter(|(_, queue)| !queue.is_empty())
+            .min_by_key(|(_, queue)| queue[0])?;
+        queue.pop_front()
+    }
+}
+
+/// Steps a grid of cells until it stops changing, generation by generation or pass by pass, see
+/// [`Update`].
+///
+/// Only cells in the neighbourhood of a cell that changed are re-examined, so `rule` must depend
+/// only on the cell itself and its neighbours. Each cell is examined once at the start and then
+/// once per change around it, so a run where cells change a bounded number of times is linear in
+/// the size of the grid.
+pub struct Automaton<T, N, R> {
+    grid: Grid<T>,
+    update: Update,
+    neighbourhood: N,
+    rule: R,
+    /// Cells to examine in the next step.
+    frontier: Frontier,
+    queued: Vec<bool>,
+    /// Cells changed by the last step, in the order they were changed.
+    changed: Vec<usize>,
+    history: Vec<StepStats>,
+    snapshots: Option<Vec<Grid<T>>>,
+}
+
+impl<T, N, I, R> Automaton<T, N, R>
+where
+    T: Clone,
+    N: Fn([usize; 2]) -> I,
+    I: IntoIterator<Item=[usize; 2]>,
+    R: Fn(&T, &mut dyn Iterator<Item=&T>) -> Option<T>,
+{
+    /// `neighbourhood` returns the positions of the neighbours of a cell. `rule` receives the state
+    /// of a cell and of its neighbours, and returns the new state of the cell if it changes.
+    pub fn new(grid: Grid<T>, update: Update, neighbourhood: N, rule: R) -> Self {
+        let num_cells = grid.cells.len();
+        let mut frontier = Frontier::default();
+        for i in 0..num_cells {
+            frontier.push(0, i);
+        }
+        Automaton {
+            grid,
+            update,
+            neighbourhood,
+            rule,
+            frontier,
+            queued: vec![true; num_cells],
+            changed: Vec::new(),
+            history: Vec::new(),
+            snapshots: None,
+        }
+    }
+
+    /// Keep a copy of the grid after every generation. The initial grid is the first snapshot.
+    pub fn record_snapshots(&mut self) {
+        self.snapshots.get_or_insert_with(|| vec![self.grid.clone()]);
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn history(&self) -> &[StepStats] {
+        &self.history
+    }
+
+    pub fn snapshots(&self) -> &[Grid<T>] {
+        self.snapshots.as_deref().unwrap_or_default()
+    }
+
+    /// Positions of the cells changed by the last step.
+    pub fn changed(&self) -> impl Iterator<Item=[usize; 2]> {
+        self.changed.iter().map(|&i| self.grid.position(i))
+    }
+
+    /// Queues the neighbours of the changed cell `i`, and `i` itself, for the pass after the one
+    /// in progress, or for the current pass if it hasn't reached them yet.
+    fn queue_around(&mut self, i: usize, current: &mut Frontier) {
+        let pos = self.grid.position(i);
+        for p in (self.neighbourhood)(pos).into_iter().chain([pos]) {
+            let j = self.grid.index(p);
+            if self.queued[j] {
+                continue;
+            }
+            self.queued[j] = true;
+            let offset = j as isize - i as isize;
+            if self.update == Update::InPlace && j > i {
+                current.push(offset, j);
+            } else {
+                self.frontier.push(offset, j);
+            }
+        }
+    }
+
+    /// Advances one generation, or makes one pass for [`Update::InPlace`]. Returns the number of
+    /// cells that changed.
+    pub fn step(&mut self) -> usize {
+        let mut current = mem::take(&mut self.frontier);
+        let mut examined = 0;
+        let mut changes = Vec::new();
+        self.changed.clear();
+        while let Some(i) = current.pop() {
+            examined += 1;
+            self.queued[i] = false;
+            let pos = self.grid.position(i);
+            let grid = &self.grid;
+            let mut neighbours = (self.neighbourhood)(pos).into_iter().map(|p| &grid[p]);
+            let Some(new_state) = (self.rule)(&grid.cells[i], &mut neighbours) else { continue };
+            self.changed.push(i);
+            match self.update {
+                Update::Synchronous => changes.push(new_state),
+                Update::InPlace => {
+                    self.grid.cells[i] = new_state;
+                    self.queue_around(i, &mut current);
+                }
+            }
+        }
+
+        if self.update == Update::Synchronous {
+            for (k, new_state) in changes.into_iter().enumerate() {
+                self.grid.cells[self.changed[k]] = new_state;
+            }
+            for k in 0..self.changed.len() {
+                self.queue_around(self.changed[k], &mut current);
+            }
+        }
+
+        let num_changed = self.changed.len();
+        self.history.push(StepStats { examined, changed: num_changed });
+        if let Some(snapshots) = &mut self.snapshots {
+            snapshots.push(self.grid.clone());
+        }
+        num_changed
+    }
+
+    /// Steps until one makes no changes. Returns the number of steps run, including the final one.
+    pub fn run(&mut self) -> usize {
+        let start = self.history.len();
+        while self.step() != 0 {}
+        self.history.len() - start
+    }
+}
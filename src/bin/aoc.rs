@@ -0,0 +1,449 @@
+use std::{env, io};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+use aoc_2025::alloc::{self, AllocStats, CountingAllocator};
+use aoc_2025::answers::{self, Assessment, Attempt, RecordedAnswers, Verdict};
+use aoc_2025::generate::{self, describe_size, Size};
+use aoc_2025::progress::Style;
+use aoc_2025::runner::{find_day, run_all, Answer, Day, Run, RunOptions, Spread, Timings, DAYS};
+use aoc_2025::{extract, scaffold};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc run <day> [--input PATH] [--time] [--mem] [--repeat N] [--timeout SECS] [--format text|json]");
+    eprintln!("       aoc run --all [--mem] [--timeout SECS] [--format text|json]");
+    eprintln!("       aoc answer <day> [<part> correct|too-high|too-low|wrong <answer>]");
+    eprintln!("       aoc generate <day> [--seed N] [--records N] [--width N]");
+    eprintln!("       aoc new <day>");
+    eprintln!("       aoc extract <day> [PAGE]");
+    eprintln!();
+    eprintln!("Inputs are read from local/inputs/dayNN.txt unless --input is given.");
+    eprintln!("Answers are checked against the attempts recorded with aoc answer, kept in {}.", answers::DEFAULT_PATH);
+    eprintln!("extract reads a saved puzzle page, local/pages/dayNN.html by default, into examples/.");
+    eprintln!("--mem counts heap allocations per phase: peak use, number of allocations and bytes.");
+    eprintln!("--timeout stops each part after SECS seconds, reporting where its search got to.");
+    eprintln!("Solvers going through records one by one show their progress when stderr is a terminal.");
+    eprintln!("--format json also reports per-record detail for the days that have it.");
+    eprintln!();
+    eprintln!("Size knobs per day:");
+    for day in generate::DAYS {
+        let (records, width) = describe_size(day).unwrap();
+        eprintln!("  day {day:2}: records = {records}, width = {width}");
+    }
+    process::exit(2);
+}
+
+/// Reports a mistake in the arguments, followed by the usage.
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!();
+    usage()
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, value: Option<String>) -> T {
+    value.and_then(|s| s.parse().ok()).unwrap_or_else(|| usage_error(&format!("{arg} needs a number")))
+}
+
+fn parse_timeout(value: Option<String>) -> Duration {
+    value.and_then(|s| Duration::try_from_secs_f64(s.parse().ok()?).ok()).unwrap_or_else(|| usage_error("--timeout needs a number of seconds"))
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn parse_format(value: Option<String>) -> Format {
+    match value.as_deref() {
+        Some("text") => Format::Text,
+        Some("json") => Format::Json,
+        _ => usage(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_optional(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "null".to_string())
+}
+
+fn json_alloc(stats: AllocStats) -> String {
+    if !alloc::is_enabled() {
+        return "null".to_string();
+    }
+    format!("{{\"peak\":{},\"count\":{},\"bytes\":{}}}", stats.peak, stats.count, stats.bytes)
+}
+
+fn json_part(part: u32, answer: &Answer, assessment: Option<&Assessment>, time: Duration, memory: AllocStats, records: Option<&Vec<String>>) -> String {
+    let correct = assessment.and_then(Assessment::is_correct);
+    let records = records.map(|records| {
+        format!("[{}]", records.iter().map(|r| json_string(r)).collect::<Vec<_>>().join(","))
+    });
+    format!(
+        "{{\"part\":{part},\"answer\":{},\"error\":{},\"correct\":{},\"check\":{},\"time_ns\":{},\"alloc\":{},\"records\":{}}}",
+        json_optional(answer.as_ref().ok().map(|a| json_string(a))),
+        json_optional(answer.as_ref().err().map(|e| json_string(e))),
+        json_optional(correct.map(|c| c.to_string())),
+        json_optional(assessment.map(|a| json_string(&a.to_string()))),
+        time.as_nanos(),
+        json_alloc(memory),
+        json_optional(records),
+    )
+}
+
+/// One day's results as a single-line JSON object.
+fn json_day(day: &Day, path: &Path, result: &io::Result<Run>, recorded: &RecordedAnswers) -> String {
+    let path = json_string(&path.display().to_string());
+    let run = match result {
+        Ok(run) => run,
+        Err(e) => {
+            let error = json_string(&e.to_string());
+            return format!("{{\"day\":{},\"input\":{{\"path\":{path}}},\"error\":{error},\"parts\":[]}}", day.day);
+        }
+    };
+    let assess = |part, answer: &Answer| answer.as_ref().ok().map(|answer| recorded.assess(day.day, part, answer));
+    let memory = &run.timings.memory;
+    let mut parts = vec![json_part(1, &run.answers.part1, assess(1, &run.answers.part1).as_ref(), run.timings.part1, memory.part1, run.records.part1.as_ref())];
+    if let (Some(answer), Some(time), Some(part2_memory)) = (&run.answers.part2, run.timings.part2, memory.part2) {
+        parts.push(json_part(2, answer, assess(2, answer).as_ref(), time, part2_memory, run.records.part2.as_ref()));
+    }
+    format!(
+        "{{\"day\":{},\"input\":{{\"path\":{path},\"hash\":\"fnv1a64:{:016x}\"}},\"load_ns\":{},\"parse_ns\":{},\"parse_alloc\":{},\"parts\":[{}]}}",
+        day.day,
+        run.input_hash,
+        run.timings.load.as_nanos(),
+        run.timings.parse.as_nanos(),
+        json_alloc(memory.parse),
+        parts.join(","),
+    )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{bytes} B") } else { format!("{value:.1} {}", UNITS[unit]) }
+}
+
+/// Allocations of each phase of a run. They don't vary between repeats the way times do, so only
+/// one run is shown.
+fn print_memory(timings: &Timings) {
+    let memory = &timings.memory;
+    let phases = [
+        ("load", Some(memory.load)),
+        ("parse", Some(memory.parse)),
+        ("part 1", Some(memory.part1)),
+        ("part 2", memory.part2),
+        ("total", Some(memory.overall)),
+    ];
+    println!("  {:<8}{:>12}{:>12}{:>12}", "", "peak", "allocs", "allocated");
+    for (name, stats) in phases {
+        if let Some(stats) = stats {
+            println!("  {name:<8}{:>12}{:>12}{:>12}", format_bytes(stats.peak as u64), stats.count, format_bytes(stats.bytes));
+        }
+    }
+}
+
+fn print_timings(runs: &[Timings]) {
+    type Phase = fn(&Timings) -> Option<Duration>;
+    let phases: [(&str, Phase); 5] = [
+        ("load", |t| Some(t.load)),
+        ("parse", |t| Some(t.parse)),
+        ("part 1", |t| Some(t.part1)),
+        ("part 2", |t| t.part2),
+        ("total", |t| Some(t.total())),
+    ];
+
+    if runs.len() == 1 {
+        for (name, phase) in phases {
+            if let Some(duration) = phase(&runs[0]) {
+                println!("  {name:<8}{duration:>12.2?}");
+            }
+        }
+        return;
+    }
+
+    println!("  {:<8}{:>12}{:>12}{:>12}  ({} runs)", "", "min", "median", "max", runs.len());
+    for (name, phase) in phases {
+        let samples = runs.iter().filter_map(phase).collect::<Vec<_>>();
+        if samples.is_empty() {
+            continue;
+        }
+        let spread = Spread::of(samples);
+        println!("  {name:<8}{:>12.2?}{:>12.2?}{:>12.2?}", spread.min, spread.median, spread.max);
+    }
+}
+
+/// Shown in place of the answer for days without a second part.
+const NO_PART: &str = "—";
+
+fn format_answer(answer: &Answer) -> String {
+    match answer {
+        Ok(answer) => answer.clone(),
+        Err(message) => format!("failed: {message}"),
+    }
+}
+
+fn check_answer(recorded: &RecordedAnswers, day: u32, part: u32, answer: &Answer) -> String {
+    match answer {
+        Ok(answer) => recorded.assess(day, part, answer).to_string(),
+        Err(_) => "failed".to_string(),
+    }
+}
+
+fn run_all_command(mut args: impl Iterator<Item = String>) -> io::Result<()> {
+    let mut format = Format::Text;
+    let mut timeout = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = parse_format(args.next()),
+            "--mem" => alloc::enable(),
+            "--timeout" => timeout = Some(parse_timeout(args.next())),
+            _ => usage(),
+        }
+    }
+
+    let recorded = RecordedAnswers::load(Path::new(answers::DEFAULT_PATH))?;
+    let start = Instant::now();
+    let progress = io::stderr().is_terminal().then_some(Style::Lines);
+    let results = run_all(RunOptions { records: format == Format::Json, timeout, progress });
+    let wall_time = start.elapsed();
+
+    if format == Format::Json {
+        let days = DAYS.iter().zip(&results)
+            .map(|(day, result)| json_day(day, &day.default_input_path(), result, &recorded))
+            .collect::<Vec<_>>();
+        println!("{{\"wall_time_ns\":{},\"days\":[\n{}\n]}}", wall_time.as_nanos(), days.join(",\n"));
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    for (day, result) in DAYS.iter().zip(results) {
+        match result {
+            Ok(Run { answers, timings, .. }) => {
+                let parts = [
+                    (1, Some(answers.part1), Some(timings.part1), Some(timings.memory.part1)),
+                    (2, answers.part2, timings.part2, timings.memory.part2),
+                ];
+                for (part, answer, time, memory) in parts {
+                    if let (Some(answer), Some(time), Some(memory)) = (answer, time, memory) {
+                        let check = check_answer(&recorded, day.day, part, &answer);
+                        let peak = format_bytes(memory.peak as u64);
+                        rows.push([day.day.to_string(), part.to_string(), format_answer(&answer), check, format!("{time:.2?}"), peak]);
+                    } else {
+                        rows.push([day.day.to_string(), part.to_string(), NO_PART.to_string(), String::new(), String::new(), String::new()]);
+                    }
+                }
+            }
+            Err(e) => {
+                let error = format!("{}: {e}", day.default_input_path().display());
+                rows.push([day.day.to_string(), "-".to_string(), error, "failed".to_string(), "-".to_string(), "-".to_string()]);
+            }
+        }
+    }
+
+    let header = ["Day", "Part", "Answer", "Check", "Time", "Peak"].map(String::from);
+    let mut widths = header.each_ref().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let [day_w, part_w, answer_w, check_w, time_w, peak_w] = widths;
+    for [day, part, answer, check, time, peak] in std::iter::once(&header).chain(&rows) {
+        let line = format!("{day:>day_w$}  {part:>part_w$}  {answer:<answer_w$}  {check:<check_w$}  {time:>time_w$}");
+        if alloc::is_enabled() {
+            println!("{line}  {peak:>peak_w$}");
+        } else {
+            println!("{line}");
+        }
+    }
+    println!();
+    println!("Wall time: {wall_time:.2?}");
+    Ok(())
+}
+
+fn run_command(mut args: impl Iterator<Item = String>) -> io::Result<()> {
+    let first = args.next().unwrap_or_else(|| usage());
+    if first == "--all" {
+        return run_all_command(args);
+    }
+    let day_number = first.parse::<u32>().unwrap_or_else(|_| usage());
+    let day = find_day(day_number).unwrap_or_else(|| usage_error(&format!("No solver for day {day_number}")));
+    let mut input_path = None;
+    let mut show_time = false;
+    let mut repeat = 1;
+    let mut format = Format::Text;
+    let mut timeout = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = parse_format(args.next()),
+            "--timeout" => timeout = Some(parse_timeout(args.next())),
+            "--input" => input_path = Some(PathBuf::from(args.next().unwrap_or_else(|| usage_error("--input needs a path")))),
+            "--time" => show_time = true,
+            "--mem" => alloc::enable(),
+            "--repeat" => repeat = parse_number(&arg, args.next()),
+            _ => usage(),
+        }
+    }
+    if repeat == 0 {
+        usage_error("--repeat needs at least one run");
+    }
+    let input_path = input_path.unwrap_or_else(|| day.default_input_path());
+    let progress = io::stderr().is_terminal().then_some(Style::Bar);
+
+    if format == Format::Json {
+        if repeat != 1 || show_time {
+            usage_error("--repeat and --time only apply to text output, --mem adds to the JSON");
+        }
+        let recorded = RecordedAnswers::load(Path::new(answers::DEFAULT_PATH))?;
+        println!("{}", json_day(day, &input_path, &day.run(&input_path, RunOptions { records: true, timeout, progress }), &recorded));
+        return Ok(());
+    }
+
+    let mut answers = None;
+    let mut runs = Vec::with_capacity(repeat);
+    for _ in 0..repeat {
+        let run = day.run(&input_path, RunOptions { records: false, timeout, progress })
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", input_path.display())))?;
+        // Where a timed out search got to varies from run to run
+        if let Some(previous) = &answers
+            && timeout.is_none()
+        {
+            assert_eq!(previous, &run.answers, "Answers changed between runs");
+        }
+        answers = Some(run.answers);
+        runs.push(run.timings);
+    }
+
+    let answers = answers.unwrap();
+    let recorded = RecordedAnswers::load(Path::new(answers::DEFAULT_PATH))?;
+    println!("Day {day_number}");
+    for (part, answer) in [(1, Some(&answers.part1)), (2, answers.part2.as_ref())] {
+        let Some(answer) = answer else {
+            println!("  Part {part}: {NO_PART}");
+            continue;
+        };
+        let assessment = answer.as_ref().ok().map(|answer| recorded.assess(day_number, part, answer));
+        match assessment {
+            Some(Assessment::Verified) => println!("  Part {part}: {} (verified)", format_answer(answer)),
+            Some(Assessment::Unchecked) | None => println!("  Part {part}: {}", format_answer(answer)),
+            Some(warning) => {
+                println!("  Part {part}: {}", format_answer(answer));
+                eprintln!("Warning: part {part} answer is {warning}");
+            }
+        }
+    }
+    if show_time {
+        print_timings(&runs);
+    }
+    if alloc::is_enabled() {
+        print_memory(&runs[0]);
+    }
+    Ok(())
+}
+
+fn answer_command(mut args: impl Iterator<Item = String>) -> io::Result<()> {
+    let day = args.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or_else(|| usage());
+    let path = Path::new(answers::DEFAULT_PATH);
+    if let Some(part) = args.next() {
+        let part = part.parse::<u32>().unwrap_or_else(|_| usage());
+        let verdict = args.next().and_then(|s| Verdict::from_keyword(&s)).unwrap_or_else(|| usage());
+        let answer = args.next().unwrap_or_else(|| usage());
+        let previous = RecordedAnswers::load(path)?.assess(day, part, &answer);
+        if matches!(previous, Assessment::KnownWrong(_)) {
+            eprintln!("Warning: {answer} was {previous}");
+        }
+        RecordedAnswers::append(path, day, part, &Attempt { verdict, answer })?;
+    }
+
+    let recorded = RecordedAnswers::load(path)?;
+    for part in [1, 2] {
+        for attempt in recorded.attempts(day, part) {
+            println!("Day {day} part {part}: {:<24} {}", attempt.answer, attempt.verdict);
+        }
+    }
+    Ok(())
+}
+
+fn generate_command(mut args: impl Iterator<Item = String>) -> io::Result<()> {
+    let day = args.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or_else(|| usage());
+    let mut seed = 0;
+    let mut size = Size::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse_number(&arg, args.next()),
+            "--records" => size.records = Some(parse_number(&arg, args.next())),
+            "--width" => size.width = Some(parse_number(&arg, args.next())),
+            _ => usage(),
+        }
+    }
+
+    let input = generate::generate(day, seed, &size)?;
+    io::stdout().lock().write_all(input.as_bytes())
+}
+
+fn new_command(mut args: impl Iterator<Item = String>) -> io::Result<()> {
+    let day = args.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or_else(|| usage());
+    if args.next().is_some() {
+        usage();
+    }
+    // Paths are relative to the repository root, like the inputs
+    let root = Path::new("");
+    if !root.join("src/lib.rs").exists() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "aoc new needs to run from the repository root"));
+    }
+    for path in scaffold::new_day(root, day)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn extract_command(mut args: impl Iterator<Item = String>) -> io::Result<()> {
+    let day = args.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or_else(|| usage());
+    let page = args.next().map_or_else(|| PathBuf::from(format!("local/pages/day{day:02}.html")), PathBuf::from);
+    if args.next().is_some() {
+        usage();
+    }
+    let entries = extract::extract(day, &page)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", page.display())))?;
+    for entry in entries {
+        println!("{} part {}: {}", entry.file, entry.part, entry.answer);
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        Some("run") => run_command(args),
+        Some("answer") => answer_command(args),
+        Some("generate") => generate_command(args),
+        Some("new") => new_command(args),
+        Some("extract") => extract_command(args),
+        _ => usage(),
+    }
+}
@@ -1,11 +1,32 @@
-use std::io;
+use std::{env, io};
+use aoc_2025::day01::parse_rotation;
+use aoc_2025::dial::Dial;
 
 fn main() -> io::Result<()> {
-    const FULL_TURN: i32 = 100;
+    let mut size = 100;
+    let mut start = 50;
+    let mut targets = Vec::new();
+    let mut show_trace = false;
 
-    let mut dial_pos = 50i32;
-    let mut zero_park_count = 0;
-    let mut zero_crossings = 0;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().and_then(|s| s.parse::<i64>().ok()).unwrap_or_else(|| panic!("{arg} needs a number"));
+        match arg.as_str() {
+            "--size" => size = value(),
+            "--start" => start = value(),
+            "--target" => targets.push(value()),
+            "--trace" => show_trace = true,
+            _ => panic!("Unknown argument {arg}"),
+        }
+    }
+    if targets.is_empty() {
+        targets.push(0);
+    }
+    let label = if targets == [0] { "Zero" } else { "Target" };
+
+    let mut dial = Dial::new(size, start, &targets);
+    let mut park_count = 0;
+    let mut crossings = 0;
 
     for line in io::stdin().lines() {
         let line = line?;
@@ -13,26 +34,18 @@ fn main() -> io::Result<()> {
             break;
         }
 
-        let mut line_chars = line.chars();
-        let movement = match line_chars.next() {
-            Some('L') => -line_chars.as_str().parse::<i32>().unwrap(),
-            Some('R') => line_chars.as_str().parse::<i32>().unwrap(),
-            c => panic!("Unexpected line prefix: {c:?}"),
-        };
-
-        let adjusted_dial = if movement < 0 {
-            (dial_pos - 1).rem_euclid(FULL_TURN)
-        } else {
-            dial_pos
-        };
-        zero_crossings += (adjusted_dial + movement).div_euclid(FULL_TURN).abs();
-        dial_pos = (dial_pos + movement).rem_euclid(FULL_TURN);
-        if dial_pos == 0 {
-            zero_park_count += 1;
+        let movement = parse_rotation(&line);
+        let event = dial.rotate(movement);
+        if show_trace {
+            println!("{line}: {} -> {}, passes {}{}", event.start, event.end, event.passes, if event.lands { ", lands" } else { "" });
+        }
+        crossings += event.passes;
+        if event.lands {
+            park_count += 1;
         }
     }
 
-    println!("Zero parks: {zero_park_count}");
-    println!("Zero crossings: {zero_crossings}");
+    println!("{label} parks: {park_count}");
+    println!("{label} crossings: {crossings}");
     Ok(())
 }
@@ -1,7 +1,43 @@
-use std::io;
+use std::{env, io};
+use aoc_2025::day03::{joltage, parse_bank, select_batteries};
+use aoc_2025::integer::{BigUint, Integer};
+
+/// Returns `None` if the joltages don't fit in `T`.
+fn solve<T: Integer>(banks: &[Vec<u8>], selections: &[Vec<usize>]) -> Option<(Vec<T>, T)> {
+    let mut values = Vec::with_capacity(banks.len());
+    let mut total_joltage = T::zero();
+    for (bank, positions) in banks.iter().zip(selections) {
+        let value = joltage::<T>(bank, positions)?;
+        total_joltage = total_joltage.checked_add(&value)?;
+        values.push(value);
+    }
+    Some((values, total_joltage))
+}
+
+fn print_results<T: Integer>(num_batteries: usize, (values, total_joltage): (Vec<T>, T), selections: Option<&[Vec<usize>]>) {
+    for (i, value) in values.iter().enumerate() {
+        match selections {
+            Some(selections) => println!("Max: {value} (positions {:?})", selections[i]),
+            None => println!("Max: {value}"),
+        }
+    }
+    println!("Total joltage ({num_batteries} batteries): {total_joltage}");
+}
 
 fn main() -> io::Result<()> {
-    let mut total_joltage: u64 = 0;
+    let mut show_positions = false;
+    let mut battery_counts = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--positions" => show_positions = true,
+            _ => battery_counts.push(arg.parse::<usize>().expect("Battery count must be a number")),
+        }
+    }
+    if battery_counts.is_empty() {
+        battery_counts.push(12);
+    }
+
+    let mut banks = Vec::new();
 
     for line in io::stdin().lines() {
         let line = line?;
@@ -9,33 +45,19 @@ fn main() -> io::Result<()> {
             break;
         }
 
-        let bank: Vec<_> = line
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as u8)
-            .collect();
-
-        const NUM_BATTERIES: usize = 12;
-
-        let mut value = 0;
-        let mut start_i = 0;
-        assert!(bank.len() >= NUM_BATTERIES);
-
-        for iteration in 1..=NUM_BATTERIES {
-            let mut max_d = 0;
-            for i in start_i..(bank.len() - NUM_BATTERIES + iteration) {
-                if bank[i] > max_d {
-                    max_d = bank[i];
-                    start_i = i + 1;
-                }
-            }
-            value = 10 * value + u64::from(max_d);
-        }
-
-        println!("Max: {value}");
-        total_joltage += value;
+        banks.push(parse_bank(&line));
     }
 
-    println!("Total joltage: {total_joltage}");
+    for num_batteries in battery_counts {
+        let selections = banks.iter()
+            .map(|bank| select_batteries(bank, num_batteries))
+            .collect::<Vec<_>>();
+        let shown_selections = show_positions.then_some(&selections[..]);
+        match solve::<u64>(&banks, &selections) {
+            Some(results) => print_results(num_batteries, results, shown_selections),
+            None => print_results(num_batteries, solve::<BigUint>(&banks, &selections).unwrap(), shown_selections),
+        }
+    }
 
     Ok(())
 }
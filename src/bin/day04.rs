@@ -1,68 +1,78 @@
-use std::{io, iter};
+use std::fs::File;
+use std::io::BufWriter;
+use std::{env, io, process};
+use aoc_2025::animation::{write_removal_html, AnimationOptions, Crop};
+use aoc_2025::automaton::Grid;
+use aoc_2025::day04::{remove_rolls, Semantics};
+use aoc_2025::debug;
+use aoc_2025::log::{self, Level};
+
+fn usage(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!();
+    eprintln!("Usage: day04 [sync|greedy] [--animate PATH] [--frame-step N] [--crop X,Y,WIDTH,HEIGHT]");
+    eprintln!();
+    eprintln!("Removal is greedy unless sync is given, as it has always been for this binary.");
+    process::exit(2);
+}
 
 fn main() -> io::Result<()> {
-    let mut width = 0;
-    let mut height = 2;
-    let mut grid: Vec<u8> = Vec::new();
+    // The in-place scan this binary started out with, see `Semantics::Greedy`
+    let mut semantics = Semantics::Greedy;
+    let mut animation_path = None;
+    let mut animation_options = AnimationOptions::default();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--animate" => animation_path = Some(args.next().unwrap_or_else(|| usage("Missing animation path"))),
+            "--frame-step" => animation_options.frame_step = args.next().and_then(|s| s.parse().ok()).unwrap_or_else(|| usage("Invalid frame step")),
+            "--crop" => animation_options.crop = Some(args.next().as_deref().and_then(Crop::parse).unwrap_or_else(|| usage("Crop must be x,y,width,height"))),
+            _ => semantics = Semantics::parse(&arg).unwrap_or_else(|| usage("Semantics must be one of: sync, greedy")),
+        }
+    }
+
+    let mut rows = Vec::new();
 
     for line in io::stdin().lines() {
         let line = line?;
         if line.is_empty() {
             break;
         }
+        rows.push(line.chars().map(|c| c == '@').collect::<Vec<_>>());
+    }
 
-        if width == 0 {
-            width = line.len() + 2;
-            println!("width: {width}");
-            grid.extend(iter::repeat_n(0, width));
-        }
-        assert_eq!(width, line.len() + 2);
-        grid.push(0);
-        grid.extend(line.chars().map(|c| if c == '@' { 1 } else { 0 }));
-        grid.push(0);
-        height += 1;
+    let grid = Grid::from_rows(rows);
+    debug!("width: {}", grid.width());
+    let removal = remove_rolls(&grid, semantics);
+
+    if let Some(path) = &animation_path {
+        let mut out = BufWriter::new(File::create(path)?);
+        write_removal_html(&mut out, &grid, &removal.removed_in, &animation_options)?;
     }
-    grid.extend(iter::repeat_n(0, width));
 
-    let mut total_reachable = 0;
-    let mut reachable_first_iteration = 0;
-    let mut iterations = 0;
-    loop {
-        let mut num_reachable: u32 = 0;
-        for y in 1..(height - 1) {
-            for x in 1..(width - 1) {
-                if grid[y * width + x] == 0 {
-                    print!(".");
-                    continue;
-                }
-                let neighbors: u8 = (-1..=1)
-                    .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
-                    .filter(|&(dx, dy)| dx != 0 || dy != 0)
-                    .map(|(dx, dy)| grid[y.strict_add_signed(dy) * width + x.strict_add_signed(dx)])
-                    .sum();
-                if neighbors < 4 {
-                    num_reachable += 1;
-                    grid[y * width + x] = 0;
-                    print!("x");
-                } else {
-                    print!("@");
-                }
+    // The animation replaces the per-iteration dump
+    if animation_path.is_none() && log::enabled(module_path!(), Level::Debug) {
+        for generation in 0..=removal.removed_per_generation.len() {
+            debug!("Generation {generation}:");
+            for (row, removed_row) in grid.rows().zip(removal.removed_in.rows()) {
+                let line = row.iter().zip(removed_row)
+                    .map(|(&roll, &removed_in)| match removed_in {
+                        _ if !roll => '.',
+                        Some(g) if g < generation => '.',
+                        Some(g) if g == generation => 'x',
+                        _ => '@',
+                    })
+                    .collect::<String>();
+                debug!("{line}");
             }
-            println!();
-        }
-
-        if iterations == 0 {
-            reachable_first_iteration = num_reachable;
-        }
-        iterations += 1;
-        if num_reachable == 0 {
-            break;
         }
-        total_reachable += num_reachable;
     }
 
-    println!("Reachable first iter: {reachable_first_iteration}");
-    println!("Reachable total: {total_reachable}");
-    println!("Iterations: {iterations}");
+    let removed = &removal.removed_per_generation;
+    println!("Reachable first iter: {}", removed.first().unwrap_or(&0));
+    println!("Reachable total: {}", removed.iter().sum::<usize>());
+    // Including the final pass that finds nothing to remove
+    println!("Iterations: {}", removed.len() + 1);
     Ok(())
 }
@@ -1,23 +1,60 @@
-use std::io;
+use std::{env, io};
 use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::process;
+use aoc_2025::day05::{count_fresh_streaming, freshness_report, parse_range, NumberedRange};
+use aoc_2025::interval_set::IntervalSet;
+
+fn usage() -> ! {
+    eprintln!("Usage: day05p1 [--report | --stream [IDS_PATH]]");
+    eprintln!();
+    eprintln!("Ranges are read from stdin, followed by the ids unless --stream is given a path.");
+    eprintln!("--stream needs the ids sorted, such as by sort -n. Ids listed more than once count once.");
+    process::exit(2);
+}
 
 fn main() -> io::Result<()> {
+    let mut report_mode = false;
+    let mut stream_mode = false;
+    let mut ids_path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--report" => report_mode = true,
+            "--stream" => stream_mode = true,
+            _ if arg.starts_with('-') || ids_path.is_some() => usage(),
+            _ => ids_path = Some(arg),
+        }
+    }
+    // Only streaming reads the ids from a file, and it keeps none of them around to report on
+    if (ids_path.is_some() && !stream_mode) || (report_mode && stream_mode) {
+        usage();
+    }
+
     let mut ranges = Vec::new();
     let mut ingredients = BTreeSet::new();
+    let mut stdin = io::stdin().lock();
 
-    for line in io::stdin().lines() {
+    for (line_i, line) in (&mut stdin).lines().enumerate() {
         let line = line?;
         if line.is_empty() {
             break;
         }
 
-        let (range_begin, range_end) = line.split_once('-').unwrap();
-        let range_begin = range_begin.parse::<u64>().unwrap();
-        let range_end = range_end.parse::<u64>().unwrap();
-        ranges.push(range_begin..=range_end);
+        ranges.push(NumberedRange { line: line_i + 1, range: parse_range(&line) });
+    }
+
+    if stream_mode {
+        let fresh_ranges: IntervalSet<u64> = ranges.into_iter().map(|r| r.range).collect();
+        let fresh_ingredients = match ids_path {
+            Some(path) => count_fresh_streaming(&fresh_ranges, BufReader::new(File::open(path)?))?,
+            None => count_fresh_streaming(&fresh_ranges, stdin)?,
+        };
+        println!("Fresh ingredients: {}", fresh_ingredients);
+        return Ok(());
     }
 
-    for line in io::stdin().lines() {
+    for line in stdin.lines() {
         let line = line?;
         if line.is_empty() {
             break;
@@ -27,12 +64,24 @@ fn main() -> io::Result<()> {
         ingredients.insert(ingredient_id);
     }
 
-    let mut fresh_ingredients = 0;
-    for range in ranges {
-        // `.count()` consumes the iterator to ensure all elements are removed
-        fresh_ingredients += ingredients.extract_if(range, |_| true).count();
+    if report_mode {
+        let ids = ingredients.iter().copied().collect::<Vec<_>>();
+        let report = freshness_report(&ranges, &ids);
+        for ingredient in &report.ingredients {
+            if ingredient.is_fresh() {
+                let lines_s = ingredient.covering_lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(", ");
+                println!("{}: fresh (lines {lines_s})", ingredient.id);
+            } else {
+                println!("{}: spoiled", ingredient.id);
+            }
+        }
+        for r in &report.unused_ranges {
+            println!("Line {}: {}-{} covers no ingredients", r.line, r.range.start(), r.range.end());
+        }
     }
 
+    let fresh_ranges: IntervalSet<u64> = ranges.into_iter().map(|r| r.range).collect();
+    let fresh_ingredients = ingredients.iter().filter(|&&id| fresh_ranges.contains(id)).count();
     println!("Fresh ingredients: {}", fresh_ingredients);
 
     Ok(())
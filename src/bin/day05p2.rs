@@ -1,4 +1,7 @@
 use std::io;
+use aoc_2025::day05::parse_range;
+use aoc_2025::debug;
+use aoc_2025::interval_set::IntervalSet;
 
 fn main() -> io::Result<()> {
     let mut ranges = Vec::new();
@@ -9,34 +12,21 @@ fn main() -> io::Result<()> {
             break;
         }
 
-        let (range_begin, range_end) = line.split_once('-').unwrap();
-        let range_begin = range_begin.parse::<u64>().unwrap();
-        let range_end = range_end.parse::<u64>().unwrap();
-        ranges.push((range_begin, range_end + 1));
+        ranges.push(parse_range(&line));
     }
-    ranges.sort_unstable();
+    ranges.sort_unstable_by_key(|r| (*r.start(), *r.end()));
     ranges
         .iter()
-        .for_each(|(begin, end)| println!("{}-{}", begin, end - 1));
+        .for_each(|r| debug!("{}-{}", r.start(), r.end()));
 
-    // Merge overlapping/adjacent ranges
-    ranges.dedup_by(|right, left| {
-        assert!(left.0 <= right.0);
-        if right.0 <= left.1 {
-            if left.1 < right.1 {
-                left.1 = right.1;
-            }
-            true // discards `right`
-        } else {
-            false
-        }
-    });
+    // Merges overlapping/adjacent ranges
+    let fresh_ranges: IntervalSet<u64> = ranges.into_iter().collect();
 
-    let fresh_ingredients: u64 = ranges.iter().map(|(begin, end)| end - begin).sum();
-    println!();
-    ranges
+    let fresh_ingredients = fresh_ranges.covered_len();
+    debug!("Merged:");
+    fresh_ranges
         .iter()
-        .for_each(|(begin, end)| println!("{}-{}", begin, end - 1));
+        .for_each(|r| debug!("{}-{}", r.start(), r.end()));
     println!("Fresh ingredients: {fresh_ingredients}");
 
     Ok(())
@@ -0,0 +1,79 @@
+//! Command line shared by the day 6 binaries, which only differ in the [`Reading`].
+
+use std::{env, io};
+use aoc_2025::day06::{ErrorKind, EvalError, Operators, Reading, Worksheet};
+use aoc_2025::integer::{BigUint, Integer};
+use aoc_2025::warn;
+
+/// Prints the results, or returns the overflow if `T` is too small for them.
+fn print_results<T: Integer + 'static>(
+    worksheet: &Worksheet,
+    reading: Reading,
+    definitions: &[(String, String)],
+    show_problems: bool,
+) -> io::Result<Result<(), EvalError>> {
+    let mut operators = Operators::<T>::standard();
+    for (symbol, expression) in definitions {
+        operators.define(symbol, expression)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("--op {symbol}: {e}")))?;
+    }
+
+    let results = worksheet.evaluate(reading, &operators);
+    let total = worksheet.total(reading, &operators);
+    let errors = results.iter().filter_map(|r| r.as_ref().err()).chain(total.as_ref().err());
+    if let Some(overflow) = errors.into_iter().find(|e| matches!(e.kind, ErrorKind::Overflow | ErrorKind::TotalOverflow)) {
+        return Ok(Err(overflow.clone()));
+    }
+
+    if show_problems {
+        for (problem, result) in worksheet.problems.iter().zip(&results) {
+            let operands = problem.operands(reading).join(&format!(" {} ", problem.operator));
+            match result {
+                Ok(result) => println!("column {:4}: {operands} = {result}", problem.column),
+                Err(e) => println!("column {:4}: {operands} = {}", problem.column, e.kind),
+            }
+        }
+    }
+    let total = total.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    println!("Results sum: {total}");
+    Ok(Ok(()))
+}
+
+/// Reads a worksheet from stdin and prints the sum of its results.
+///
+/// `--problems` prints each problem's result as well, and `--op SYMBOL=EXPRESSION` defines an
+/// operator, see [`Operators::define`].
+pub fn run_cli(reading: Reading) -> io::Result<()> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let mut show_problems = false;
+    let mut definitions = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--problems" => show_problems = true,
+            "--op" => {
+                let definition = args.next().unwrap_or_default();
+                let (symbol, expression) = definition.split_once('=')
+                    .ok_or_else(|| invalid("--op needs SYMBOL=EXPRESSION".to_string()))?;
+                definitions.push((symbol.to_string(), expression.to_string()));
+            }
+            _ => return Err(invalid(format!("Unknown argument {arg}"))),
+        }
+    }
+
+    let mut lines = Vec::new();
+    for line in io::stdin().lines() {
+        let line = line?;
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+
+    let worksheet = Worksheet::parse(&lines);
+    if let Err(e) = print_results::<u64>(&worksheet, reading, &definitions, show_problems)? {
+        warn!("{e}, retrying with big integers");
+        print_results::<BigUint>(&worksheet, reading, &definitions, show_problems)?.unwrap();
+    }
+    Ok(())
+}
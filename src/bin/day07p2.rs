@@ -1,40 +1,24 @@
-use std::cell::OnceCell;
-use std::collections::BTreeMap;
-use std::io;
+use std::{env, io};
+use aoc_2025::day07::{Boundary, Manifold, Rules};
 
 fn main() -> io::Result<()> {
-    let mut columns: Vec<BTreeMap<usize, OnceCell<u64>>> = Vec::new();
-
-    let mut starting_x = 0;
+    let mut rules = Rules::default();
+    if let Some(arg) = env::args().nth(1) {
+        rules.boundary = Boundary::parse(&arg).expect("Boundary must be one of: lost, wrap, reflect");
+    }
 
-    for (y, line) in io::stdin().lines().enumerate() {
+    let mut lines = Vec::new();
+    for line in io::stdin().lines() {
         let line = line?;
         if line.is_empty() {
             break;
         }
-        assert!(line.is_ascii());
-        columns.resize_with(line.len(), BTreeMap::new);
-        for (x, c) in line.as_bytes().iter().enumerate() {
-            match c {
-                b'S' => { starting_x = x; },
-                b'^' => { columns[x].insert(y, OnceCell::new()); },
-                _ => {},
-            }
-        }
-    }
-
-    fn trace_beam(x: usize, y: usize, columns: &[BTreeMap<usize, OnceCell<u64>>]) -> u64 {
-        if let Some((&y, num_beams)) = columns[x].range(y..).next() {
-            *num_beams.get_or_init(|| {
-                trace_beam(x - 1, y, columns) + trace_beam(x + 1, y, columns)
-            })
-        } else {
-            1
-        }
+        lines.push(line);
     }
 
-    let num_beams = trace_beam(starting_x, 0, &columns);
-    println!("Number of splits: {num_beams}");
+    let manifold = Manifold::parse(&lines);
+    let num_timelines = manifold.count_timelines(&rules);
+    println!("Number of timelines: {num_timelines}");
 
     Ok(())
 }
@@ -1,77 +1,7 @@
-use std::{array, io};
-use std::cmp::Reverse;
+use std::io;
 use std::collections::BTreeMap;
-
-fn sqr_distance(a: &[i64; 3], b: &[i64; 3]) -> i64 {
-    fn sqr(x: i64) -> i64 { x * x }
-    sqr(a[0] - b[0]) + sqr(a[1] - b[1]) + sqr(a[2] - b[2])
-}
-
-fn minmax<T: Ord>(a: T, b: T) -> [T; 2] {
-    if b < a { [b, a] } else { [a, b] }
-}
-
-struct UnionFind {
-    memberships: Vec<usize>,
-    set_sizes: Vec<usize>
-}
-
-impl UnionFind {
-    fn new(total_items: usize) -> UnionFind {
-        UnionFind {
-            memberships: (0..total_items).collect(),
-            set_sizes: vec![1; total_items],
-        }
-    }
-
-    /// Returns the id of the set item_id is a member of.
-    fn resolve_set(&self, item_id: usize) -> usize {
-        let mut set_id = item_id;
-        while self.memberships[set_id] != set_id {
-            set_id = self.memberships[set_id];
-        }
-        set_id
-    }
-
-    /// Merges the sets of both items together. Returns the id of the resulting set.
-    fn merge_items(&mut self, item1: usize, item2: usize) -> usize {
-        let set1 = self.resolve_set(item1);
-        let set2 = self.resolve_set(item2);
-        let [dst_set, src_set] = minmax(set1, set2);
-        if src_set != dst_set {
-            self.memberships[src_set] = dst_set;
-            self.set_sizes[dst_set] += self.set_sizes[src_set];
-            self.set_sizes[src_set] = 0;
-        }
-        dst_set
-    }
-
-    fn check_integrity(&self) {
-        // Integrity check
-        let mut recounts = vec![0; self.memberships.len()];
-        for i in 0..self.memberships.len() {
-            if self.memberships[i] == i {
-                assert_ne!(self.set_sizes[i], 0);
-            } else {
-                assert_eq!(self.set_sizes[i], 0);
-            }
-            recounts[self.resolve_set(i)] += 1;
-        }
-        assert_eq!(recounts, self.set_sizes);
-    }
-}
-
-fn max_k<const K: usize, T: Copy + Ord>(it: impl Iterator<Item=T>) -> [Option<T>; K] {
-    let mut k_largest = [None; K];
-    for x in it {
-        let insert_idx = k_largest.iter().position(|o| o.is_none_or(|y| x > y));
-        if let Some(insert_idx) = insert_idx {
-            k_largest[insert_idx..].rotate_right(1);
-            k_largest[insert_idx] = Some(x);
-        }
-    }
-    k_largest
-}
+use aoc_2025::day08::{connect_closest, largest_sets_product, parse_point};
+use aoc_2025::debug;
 
 fn main() -> io::Result<()> {
     let mut points = Vec::new();
@@ -81,44 +11,15 @@ fn main() -> io::Result<()> {
         if line.is_empty() {
             break;
         }
-
-        let mut it = line.splitn(3, ',').map(|s| s.parse().unwrap());
-        let point: [i64; 3] = array::from_fn(|_| it.next().unwrap());
-        points.push(point);
-    }
-
-    // Compute n^2 distances between all points
-    let mut distances = Vec::with_capacity(points.len().pow(2) / 2);
-    for (i, pi) in points.iter().enumerate() {
-        for (j, pj) in points.iter().enumerate().skip(i + 1) {
-            assert!(i < j);
-            let d = sqr_distance(pi, pj);
-            distances.push((d, (i, j)));
-        }
-    }
-
-    // Find 1000 pairs with the shortest distances
-    let head_len = distances.len().min(1000);
-    distances.select_nth_unstable_by_key(head_len - 1, |(d, _)| *d);
-    let head = &mut distances[..head_len];
-    head.sort_unstable_by_key(|(_, ij)| *ij);
-
-    // Union-find merge all the shortest pairs
-    let mut sets = UnionFind::new(points.len());
-    for &(_, (i, j)) in &*head {
-        sets.merge_items(i, j);
+        points.push(parse_point(&line));
     }
-    println!("membership: {:?}", sets.memberships.iter().enumerate().collect::<BTreeMap<_, _>>());
-    println!("counts: {:?}", sets.set_sizes.iter().enumerate().collect::<BTreeMap<_, _>>());
 
-    // Find k=3 largest sets
-    sets.check_integrity();
-    let mut set_counts = sets.set_sizes;
-    set_counts.select_nth_unstable_by_key(3 - 1, |&x| Reverse(x));
-    let k_largest = &set_counts[..3];
-    println!("k_largest: {k_largest:?}");
+    // Union-find merge the 1000 pairs with the shortest distances
+    let sets = connect_closest(&points, 1000);
+    debug!("membership: {:?}", sets.memberships().iter().enumerate().collect::<BTreeMap<_, _>>());
+    debug!("counts: {:?}", sets.set_sizes().iter().enumerate().collect::<BTreeMap<_, _>>());
 
-    let size_product: usize = k_largest.iter().copied().filter(|x| *x != 0).product();
+    let size_product = largest_sets_product(&sets);
     println!("Result: {size_product}");
 
     Ok(())
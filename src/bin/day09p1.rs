@@ -1,10 +1,5 @@
-use std::{array, io};
-
-fn calculate_area(a: &[u64; 2], b: &[u64; 2]) -> u64 {
-    let width = a[0].abs_diff(b[0]) + 1;
-    let height = a[1].abs_diff(b[1]) + 1;
-    width * height
-}
+use std::io;
+use aoc_2025::day09::{largest_rectangle, parse_point};
 
 fn main() -> io::Result<()> {
     let mut points = Vec::new();
@@ -15,22 +10,10 @@ fn main() -> io::Result<()> {
             break;
         }
 
-        let mut it = line.splitn(2, ',').map(|s| s.parse().unwrap());
-        let point: [u64; 2] = array::from_fn(|_| it.next().unwrap());
-        points.push(point);
-    }
-
-    // Compute all areas
-    let mut max_area = 0;
-    for (i, pi) in points.iter().enumerate() {
-        for (_j, pj) in points.iter().enumerate().skip(i + 1) {
-            let area = calculate_area(pi, pj);
-            if area > max_area {
-                max_area = area;
-            }
-        }
+        points.push(parse_point(&line));
     }
 
+    let max_area = largest_rectangle(&points);
     println!("Result: {max_area}");
     Ok(())
 }
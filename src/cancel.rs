@@ -0,0 +1,104 @@
+//! Cooperative cancellation for searches that can run for a long time.
+//!
+//! Search routines take a [`Cancel`] token and check it as they go, returning [`Cancelled`] with
+//! what they found so far once it trips. Solvers are called without one, so the runner installs
+//! its token for the current thread, see [`Cancel::current`], and solvers pass the [`Cancelled`]
+//! on as their part's error, see [`PartError`](crate::solver::PartError).
+
+use std::cell::RefCell;
+use std::fmt;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Trips when [`Cancel::cancel`] is called on it or any clone, or when its deadline passes.
+#[derive(Clone, Debug, Default)]
+pub struct Cancel {
+    flag: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Cancel> = RefCell::new(Cancel::never());
+}
+
+impl Cancel {
+    pub fn never() -> Cancel {
+        Cancel::default()
+    }
+
+    pub fn with_timeout(timeout: Duration) -> Cancel {
+        Cancel { flag: Arc::default(), deadline: Some(Instant::now() + timeout) }
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    fn timed_out(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed) || self.timed_out()
+    }
+
+    /// `Err` once the token has tripped, for searches to return with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.is_cancelled() {
+            true => Err(Cancelled { timed_out: self.timed_out(), record: None, best: None }),
+            false => Ok(()),
+        }
+    }
+
+    /// The token installed for this thread by [`Cancel::install`], or one that never trips.
+    pub fn current() -> Cancel {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Makes this the thread's [`Cancel::current`] token while `f` runs.
+    pub fn install<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|current| current.replace(self.clone()));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+        CURRENT.with(|current| current.replace(previous));
+        result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+}
+
+/// A search stopped by its [`Cancel`] token before finishing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cancelled {
+    pub timed_out: bool,
+    /// Kind of record and its 1-based number, e.g. `("machine", 37)`.
+    pub record: Option<(&'static str, usize)>,
+    /// Best bound on the answer found before stopping.
+    pub best: Option<String>,
+}
+
+impl Cancelled {
+    /// Notes the record being processed, unless an inner search already did.
+    pub fn at_record(mut self, kind: &'static str, index: usize) -> Cancelled {
+        self.record.get_or_insert((kind, index + 1));
+        self
+    }
+
+    /// Notes the best bound so far, unless an inner search already did.
+    pub fn with_best(mut self, best: impl fmt::Display) -> Cancelled {
+        self.best.get_or_insert_with(|| best.to_string());
+        self
+    }
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.timed_out { "timed out" } else { "cancelled" })?;
+        if let Some((kind, number)) = self.record {
+            write!(f, " at {kind} #{number}")?;
+        }
+        if let Some(best) = &self.best {
+            write!(f, ", best bound so far {best}")?;
+        }
+        Ok(())
+    }
+}
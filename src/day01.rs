@@ -0,0 +1,31 @@
+use crate::dial::Dial;
+use crate::solver::{input_lines, PartAnswer, Solver};
+
+pub fn parse_rotation(line: &str) -> i64 {
+    let mut line_chars = line.chars();
+    match line_chars.next() {
+        Some('L') => -line_chars.as_str().parse::<i64>().unwrap(),
+        Some('R') => line_chars.as_str().parse::<i64>().unwrap(),
+        c => panic!("Unexpected line prefix: {c:?}"),
+    }
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Vec<i64> {
+        input_lines(input).map(parse_rotation).collect()
+    }
+
+    fn part1(rotations: &Vec<i64>) -> PartAnswer {
+        let mut dial = Dial::new(100, 50, &[0]);
+        Ok(rotations.iter().filter(|&&r| dial.rotate(r).lands).count().to_string())
+    }
+
+    fn part2(rotations: &Vec<i64>) -> Option<PartAnswer> {
+        let mut dial = Dial::new(100, 50, &[0]);
+        Some(Ok(rotations.iter().map(|&r| dial.rotate(r).passes).sum::<u64>().to_string()))
+    }
+}
@@ -0,0 +1,209 @@
+use std::ops::RangeInclusive;
+use crate::integer::{BigUint, Integer};
+use crate::solver::{PartAnswer, Solver};
+
+/// Smallest number >= `val` made of `n_groups` repetitions of the same digit group.
+///
+/// Does not advance if `val` is already a repeating pattern.
+pub fn advance_to_next_double(mut val: u64, n_groups: u32) -> u64 {
+    let mut val_digits = val.ilog10() + 1;
+    if !val_digits.is_multiple_of(n_groups) {
+        // Odd number of digits, expand to next valid even-digited number
+        val_digits = val_digits.next_multiple_of(n_groups);
+        val = 10u64.pow(val_digits - 1);
+    }
+
+    let factor = 10u64.pow(val_digits / n_groups);
+    let mut best_group = 0;
+    for _ in 0..n_groups {
+        let next_group = val % factor;
+        best_group = if next_group >= best_group {
+            next_group
+        } else {
+            next_group + 1
+        };
+        val /= factor;
+    }
+    let mut result = 0;
+    for _ in 0..n_groups {
+        result = result * factor + best_group;
+    }
+    result
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RepeatedSums<T> {
+    /// Sum of numbers made of a digit group repeated at least twice.
+    pub any_groups: T,
+    /// Sum of numbers made of a digit group repeated exactly twice.
+    pub two_groups: T,
+}
+
+fn mobius(mut n: u32) -> i32 {
+    let mut result = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            result = -result;
+        }
+        p += 1;
+    }
+    if n > 1 { -result } else { result }
+}
+
+/// `1 + 10^block_digits + 10^(2*block_digits) + ...` up to `digits` digits, or `None` if it
+/// doesn't fit in `T`.
+fn repunit<T: Integer>(digits: u32, block_digits: u32) -> Option<T> {
+    let step = T::checked_pow10(block_digits)?;
+    let mut place = T::one();
+    let mut result = T::one();
+    for _ in 1..digits / block_digits {
+        place = place.checked_mul(&step)?;
+        result = result.checked_add(&place)?;
+    }
+    Some(result)
+}
+
+/// Sum of the numbers in `range` with `digits` digits that consist of a `block_digits` long digit
+/// group repeated. Returns `None` on overflow.
+///
+/// These are exactly `block * (1 + 10^block_digits + 10^(2*block_digits) + ...)`, so the sum over
+/// the valid blocks is an arithmetic series.
+fn sum_periodic<T: Integer>(range: &RangeInclusive<T>, digits: u32, block_digits: u32) -> Option<T> {
+    let Some(repunit) = repunit::<T>(digits, block_digits) else {
+        // Even the smallest such number is too large to be in the range
+        return Some(T::zero());
+    };
+    let one = T::one();
+    let lo = range.start().clone().max(T::checked_pow10(digits - 1)?);
+    let hi = match T::checked_pow10(digits) {
+        Some(limit) => range.end().clone().min(limit.checked_sub(&one)?),
+        None => range.end().clone(),
+    };
+    if lo > hi {
+        return Some(T::zero());
+    }
+    let (block_lo, rem) = lo.div_rem(&repunit);
+    let block_lo = if rem.is_zero() { block_lo } else { block_lo.checked_add(&one)? };
+    let block_hi = hi.div_rem(&repunit).0;
+    if block_lo > block_hi {
+        return Some(T::zero());
+    }
+    // One of the two factors is always even
+    let count = block_hi.checked_sub(&block_lo)?.checked_add(&one)?;
+    let series = block_lo.checked_add(&block_hi)?.checked_mul(&count)?.div_rem(&T::from_u32(2)).0;
+    repunit.checked_mul(&series)
+}
+
+/// Sums all repeated-pattern numbers in `range` without enumerating them. Returns `None` if the
+/// sums overflow `T`.
+///
+/// A number with `D` digits repeats a group if its digits have a period `d < D` dividing `D`.
+/// Summing over every such `d` would count numbers with several periods (e.g. `111111`) more than
+/// once, so the sums are combined by inclusion-exclusion over the divisors of `D`, which works out
+/// to weighting the sum for period `d` by `-mobius(D / d)`.
+pub fn sum_repeated<T: Integer>(range: RangeInclusive<T>) -> Option<RepeatedSums<T>> {
+    let mut sums = RepeatedSums { any_groups: T::zero(), two_groups: T::zero() };
+    if range.start() > range.end() {
+        return Some(sums);
+    }
+
+    let max_digits = range.end().num_digits();
+    for digits in 2..=max_digits {
+        // Positive and negative terms are kept apart since `T` is unsigned
+        let mut added = T::zero();
+        let mut subtracted = T::zero();
+        for period in (1..digits).filter(|d| digits.is_multiple_of(*d)) {
+            match -mobius(digits / period) {
+                1 => added = added.checked_add(&sum_periodic(&range, digits, period)?)?,
+                -1 => subtracted = subtracted.checked_add(&sum_periodic(&range, digits, period)?)?,
+                _ => {}
+            }
+        }
+        sums.any_groups = sums.any_groups.checked_add(&added.checked_sub(&subtracted)?)?;
+        if digits.is_multiple_of(2) {
+            sums.two_groups = sums.two_groups.checked_add(&sum_periodic(&range, digits, digits / 2)?)?;
+        }
+    }
+    Some(sums)
+}
+
+/// Sums over all the comma-separated ranges in `line`. Returns `None` if a bound or a sum doesn't
+/// fit in `T`.
+pub fn sum_ranges<T: Integer>(line: &str) -> Option<RepeatedSums<T>> {
+    let mut total = RepeatedSums { any_groups: T::zero(), two_groups: T::zero() };
+    for range in line.split(',') {
+        let (range_begin, range_end) = range.split_once('-').unwrap();
+        let sums = sum_repeated(range_begin.parse::<T>().ok()?..=range_end.parse::<T>().ok()?)?;
+        total.any_groups = total.any_groups.checked_add(&sums.any_groups)?;
+        total.two_groups = total.two_groups.checked_add(&sums.two_groups)?;
+    }
+    Some(total)
+}
+
+fn to_strings<T: Integer>(sums: RepeatedSums<T>) -> RepeatedSums<String> {
+    RepeatedSums { any_groups: sums.any_groups.to_string(), two_groups: sums.two_groups.to_string() }
+}
+
+/// [`sum_ranges`] as strings. Numbers from 64-bit ranges can't overflow the sums, anything larger
+/// falls back to arbitrary precision.
+pub fn sum_ranges_any_width(line: &str) -> RepeatedSums<String> {
+    match sum_ranges::<u128>(line) {
+        Some(sums) => to_strings(sums),
+        None => to_strings(sum_ranges::<BigUint>(line).unwrap()),
+    }
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.lines().next().unwrap().to_string()
+    }
+
+    fn part1(line: &String) -> PartAnswer {
+        Ok(sum_ranges_any_width(line).two_groups)
+    }
+
+    fn part2(line: &String) -> Option<PartAnswer> {
+        Some(Ok(sum_ranges_any_width(line).any_groups))
+    }
+}
+
+fn is_repeated(n: u64, n_groups: u32) -> bool {
+    let digits = n.checked_ilog10().unwrap_or(0) + 1;
+    if !digits.is_multiple_of(n_groups) {
+        return false;
+    }
+    let factor = 10u64.pow(digits / n_groups);
+    let group = n % factor;
+    (0..n_groups).fold(0, |result, _| result * factor + group) == n
+}
+
+/// Reference implementation for [`advance_to_next_double`] that tries every number in turn.
+#[doc(hidden)]
+pub fn advance_to_next_double_naive(val: u64, n_groups: u32) -> u64 {
+    (val..).find(|&n| is_repeated(n, n_groups)).unwrap()
+}
+
+/// Reference implementation for [`sum_repeated`] that checks every number in the range.
+#[doc(hidden)]
+pub fn sum_repeated_naive(range: RangeInclusive<u64>) -> RepeatedSums<u128> {
+    let mut sums = RepeatedSums { any_groups: 0, two_groups: 0 };
+    for n in range {
+        let digits = n.checked_ilog10().unwrap_or(0) + 1;
+        if (2..=digits).any(|n_groups| is_repeated(n, n_groups)) {
+            sums.any_groups += u128::from(n);
+        }
+        if is_repeated(n, 2) {
+            sums.two_groups += u128::from(n);
+        }
+    }
+    sums
+}
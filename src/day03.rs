@@ -0,0 +1,153 @@
+use std::cell::OnceCell;
+use crate::integer::{BigUint, Integer};
+use crate::progress::Progress;
+use crate::solver::{input_lines, PartAnswer, Solver};
+
+/// Positions of the `num_batteries` digits of `bank` that, kept in order, form the largest number.
+///
+/// Uses a monotonic stack: a digit pops smaller digits before it as long as enough digits remain
+/// after it to still pick `num_batteries` in total. Each digit is pushed and popped at most once.
+pub fn select_batteries(bank: &[u8], num_batteries: usize) -> Vec<usize> {
+    assert!(bank.len() >= num_batteries);
+
+    let mut stack: Vec<usize> = Vec::with_capacity(num_batteries);
+    for (i, &d) in bank.iter().enumerate() {
+        let remaining = bank.len() - i;
+        while stack.last().is_some_and(|&top| bank[top] < d) && stack.len() - 1 + remaining >= num_batteries {
+            stack.pop();
+        }
+        if stack.len() < num_batteries {
+            stack.push(i);
+        }
+    }
+    stack
+}
+
+/// Largest number that can be formed by picking `num_batteries` digits of `bank` in order.
+/// Returns `None` if it overflows `T`.
+pub fn max_joltage<T: Integer>(bank: &[u8], num_batteries: usize) -> Option<T> {
+    joltage(bank, &select_batteries(bank, num_batteries))
+}
+
+/// Number formed by the digits of `bank` at `positions`. Returns `None` if it overflows `T`.
+pub fn joltage<T: Integer>(bank: &[u8], positions: &[usize]) -> Option<T> {
+    let ten = T::from_u32(10);
+    positions.iter().try_fold(T::zero(), |value, &i| {
+        value.checked_mul(&ten)?.checked_add(&T::from_u32(bank[i].into()))
+    })
+}
+
+pub fn parse_bank(line: &str) -> Vec<u8> {
+    line.chars().map(|c| c.to_digit(10).unwrap() as u8).collect()
+}
+
+/// Largest joltage of each bank. Returns `None` if one of them or their sum overflows `T`.
+fn bank_maxima<T: Integer>(banks: &[Vec<u8>], num_batteries: usize) -> Option<(Vec<T>, T)> {
+    let mut progress = Progress::new("bank", banks.len());
+    let mut total = T::zero();
+    let maxima = banks.iter().map(|bank| {
+        let max = max_joltage::<T>(bank, num_batteries)?;
+        total = total.checked_add(&max)?;
+        progress.advance();
+        Some(max)
+    }).collect::<Option<Vec<_>>>()?;
+    Some((maxima, total))
+}
+
+/// Largest joltage of each bank and their sum, in `u64` unless it overflows.
+enum Maxima {
+    Fixed(Vec<u64>, u64),
+    Big(Vec<BigUint>, BigUint),
+}
+
+impl Maxima {
+    fn new(banks: &[Vec<u8>], num_batteries: usize) -> Maxima {
+        match bank_maxima(banks, num_batteries) {
+            Some((maxima, total)) => Maxima::Fixed(maxima, total),
+            None => {
+                let (maxima, total) = bank_maxima(banks, num_batteries).unwrap();
+                Maxima::Big(maxima, total)
+            }
+        }
+    }
+
+    fn total(&self) -> String {
+        match self {
+            Maxima::Fixed(_, total) => total.to_string(),
+            Maxima::Big(_, total) => total.to_string(),
+        }
+    }
+
+    fn records(&self) -> Vec<String> {
+        match self {
+            Maxima::Fixed(maxima, _) => maxima.iter().map(u64::to_string).collect(),
+            Maxima::Big(maxima, _) => maxima.iter().map(BigUint::to_string).collect(),
+        }
+    }
+}
+
+const PART1_BATTERIES: usize = 2;
+const PART2_BATTERIES: usize = 12;
+
+pub struct Banks {
+    pub banks: Vec<Vec<u8>>,
+    /// Kept from the parts, so that their records don't compute the maxima again.
+    part1: OnceCell<Maxima>,
+    part2: OnceCell<Maxima>,
+}
+
+impl Banks {
+    pub fn new(banks: Vec<Vec<u8>>) -> Banks {
+        Banks { banks, part1: OnceCell::new(), part2: OnceCell::new() }
+    }
+
+    fn part1(&self) -> &Maxima {
+        self.part1.get_or_init(|| Maxima::new(&self.banks, PART1_BATTERIES))
+    }
+
+    fn part2(&self) -> &Maxima {
+        self.part2.get_or_init(|| Maxima::new(&self.banks, PART2_BATTERIES))
+    }
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Banks;
+
+    fn parse(input: &str) -> Banks {
+        Banks::new(input_lines(input).map(parse_bank).collect())
+    }
+
+    fn part1(banks: &Banks) -> PartAnswer {
+        Ok(banks.part1().total())
+    }
+
+    fn part2(banks: &Banks) -> Option<PartAnswer> {
+        Some(Ok(banks.part2().total()))
+    }
+
+    fn part1_records(banks: &Banks) -> Option<Vec<String>> {
+        Some(banks.part1().records())
+    }
+
+    fn part2_records(banks: &Banks) -> Option<Vec<String>> {
+        Some(banks.part2().records())
+    }
+}
+
+/// Reference implementation for [`max_joltage`] that tries every choice of digits. Exponential in
+/// the size of the bank.
+#[doc(hidden)]
+pub fn max_joltage_naive(bank: &[u8], num_batteries: usize) -> u64 {
+    if num_batteries == 0 {
+        return 0;
+    }
+    (0..=bank.len() - num_batteries)
+        .map(|i| {
+            let rest = max_joltage_naive(&bank[i + 1..], num_batteries - 1);
+            u64::from(bank[i]) * 10u64.pow(num_batteries as u32 - 1) + rest
+        })
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,111 @@
+use crate::automaton::{moore_neighbourhood, Automaton, Grid, Update};
+use crate::solver::{input_lines, PartAnswer, Solver};
+
+/// Rolls with fewer neighbouring rolls than this can be removed.
+const MIN_BLOCKING_NEIGHBOURS: u8 = 4;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Semantics {
+    /// Every roll removable at the start of a generation is removed at once, and only affects the
+    /// following generation.
+    #[default]
+    Synchronous,
+    /// Rows are scanned top to bottom, left to right, and rolls are removed as soon as the scan
+    /// reaches them, so a removal also affects the cells after it in the same pass.
+    Greedy,
+}
+
+impl Semantics {
+    pub fn parse(s: &str) -> Option<Semantics> {
+        match s {
+            "sync" => Some(Semantics::Synchronous),
+            "greedy" => Some(Semantics::Greedy),
+            _ => None,
+        }
+    }
+}
+
+pub struct Removal {
+    /// Generation in which each roll was removed. `None` for empty cells and rolls never removed.
+    pub removed_in: Grid<Option<usize>>,
+    /// Number of rolls removed in each generation.
+    pub removed_per_generation: Vec<usize>,
+}
+
+/// Repeatedly removes rolls that have fewer than 4 neighbours until no more can be removed.
+///
+/// Runs on an [`Automaton`], so only the neighbours of removed rolls are ever looked at again.
+pub fn remove_rolls(grid: &Grid<bool>, semantics: Semantics) -> Removal {
+    let update = match semantics {
+        Semantics::Synchronous => Update::Synchronous,
+        Semantics::Greedy => Update::InPlace,
+    };
+    let neighbourhood = moore_neighbourhood(grid.width(), grid.height());
+    let mut automaton = Automaton::new(grid.clone(), update, neighbourhood, |&roll, neighbours| {
+        (roll && neighbours.filter(|&&n| n).count() < usize::from(MIN_BLOCKING_NEIGHBOURS)).then_some(false)
+    });
+
+    let mut removed_in = Grid::from_rows((0..grid.height()).map(|_| vec![None; grid.width()]));
+    let mut removed_per_generation = Vec::new();
+    while automaton.step() != 0 {
+        let generation = removed_per_generation.len();
+        for p in automaton.changed() {
+            removed_in[p] = Some(generation);
+        }
+        removed_per_generation.push(automaton.history()[generation].changed);
+    }
+
+    Removal { removed_in, removed_per_generation }
+}
+
+/// Reference implementation for [`remove_rolls`] that rescans the whole grid every generation.
+/// Returns the number of rolls removed in each generation.
+#[doc(hidden)]
+pub fn remove_rolls_naive(grid: &Grid<bool>, semantics: Semantics) -> Vec<usize> {
+    let neighbourhood = moore_neighbourhood(grid.width(), grid.height());
+    let mut grid = grid.clone();
+    let mut removed_per_generation = Vec::new();
+    loop {
+        // Greedy removal reads the grid being modified, synchronous removal a copy of it
+        let before = grid.clone();
+        let mut removed = 0;
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let view = if semantics == Semantics::Greedy { &grid } else { &before };
+                let neighbours = neighbourhood([x, y]).filter(|&p| view[p]).count();
+                if grid[[x, y]] && neighbours < usize::from(MIN_BLOCKING_NEIGHBOURS) {
+                    grid[[x, y]] = false;
+                    removed += 1;
+                }
+            }
+        }
+        if removed == 0 {
+            return removed_per_generation;
+        }
+        removed_per_generation.push(removed);
+    }
+}
+
+pub fn parse_grid(input: &str) -> Grid<bool> {
+    Grid::from_rows(input_lines(input).map(|line| line.chars().map(|c| c == '@').collect()))
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Grid<bool> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Grid<bool>) -> PartAnswer {
+        let removal = remove_rolls(grid, Semantics::Synchronous);
+        Ok(removal.removed_per_generation.first().unwrap_or(&0).to_string())
+    }
+
+    fn part2(grid: &Grid<bool>) -> Option<PartAnswer> {
+        let removal = remove_rolls(grid, Semantics::Synchronous);
+        Some(Ok(removal.removed_per_generation.iter().sum::<usize>().to_string()))
+    }
+}
@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+use crate::interval_set::IntervalSet;
+use crate::solver::{PartAnswer, Solver};
+
+/// A fresh ingredient range, along with the input line it was read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumberedRange {
+    pub line: usize,
+    pub range: RangeInclusive<u64>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IngredientReport {
+    pub id: u64,
+    /// Lines of all ranges containing the ingredient, in increasing order. Empty if it is spoiled.
+    pub covering_lines: Vec<usize>,
+}
+
+impl IngredientReport {
+    pub fn is_fresh(&self) -> bool {
+        !self.covering_lines.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FreshnessReport {
+    /// One entry per ingredient, sorted by id.
+    pub ingredients: Vec<IngredientReport>,
+    /// Ranges that don't contain any of the ingredients, sorted by line.
+    pub unused_ranges: Vec<NumberedRange>,
+}
+
+/// Finds which ranges contain each ingredient in a single sweep over ranges and ids sorted by
+/// position.
+///
+/// Ranges are added to an active set when the sweep reaches their start, and dropped once it
+/// passes their end, so each ingredient only looks at the ranges actually containing it.
+pub fn freshness_report(ranges: &[NumberedRange], ingredient_ids: &[u64]) -> FreshnessReport {
+    let mut by_start: Vec<usize> = (0..ranges.len()).collect();
+    by_start.sort_unstable_by_key(|&i| *ranges[i].range.start());
+    let mut ids = ingredient_ids.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let mut used = vec![false; ranges.len()];
+    // Min-heap of (range end, range index)
+    let mut active = BinaryHeap::new();
+    let mut next_range = by_start.iter().peekable();
+    let mut ingredients = Vec::with_capacity(ids.len());
+
+    for id in ids {
+        while let Some(&i) = next_range.next_if(|&&i| *ranges[i].range.start() <= id) {
+            active.push(Reverse((*ranges[i].range.end(), i)));
+        }
+        while active.peek().is_some_and(|Reverse((end, _))| *end < id) {
+            active.pop();
+        }
+
+        let mut covering_lines = active.iter()
+            .map(|Reverse((_, i))| {
+                used[*i] = true;
+                ranges[*i].line
+            })
+            .collect::<Vec<_>>();
+        covering_lines.sort_unstable();
+        ingredients.push(IngredientReport { id, covering_lines });
+    }
+
+    let mut unused_ranges = ranges.iter().zip(used)
+        .filter(|(_, used)| !used)
+        .map(|(r, _)| r.clone())
+        .collect::<Vec<_>>();
+    unused_ranges.sort_unstable_by_key(|r| r.line);
+
+    FreshnessReport { ingredients, unused_ranges }
+}
+
+/// Counts the fresh ingredients among ids read one per line from `ids`, up to the first empty line.
+///
+/// Ids are checked by binary search over the merged ranges as they're read, without being stored,
+/// so the list can be arbitrarily large. Like [`Day05`], an id listed more than once is counted
+/// once, which needs the ids in increasing order, as `sort -n` leaves them. An id smaller than the
+/// one before it is an error.
+pub fn count_fresh_streaming(fresh_ranges: &IntervalSet<u64>, mut ids: impl BufRead) -> io::Result<u64> {
+    let merged = fresh_ranges.iter().collect::<Vec<_>>();
+    let mut fresh_ingredients = 0;
+    let mut previous = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if ids.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        let id = line.parse::<u64>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid id '{line}': {e}")))?;
+        match previous {
+            Some(previous) if id < previous => {
+                let message = format!("Id {id} comes after {previous}, streamed ids must be sorted");
+                return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+            }
+            Some(previous) if id == previous => continue,
+            _ => previous = Some(id),
+        }
+        // First range not ending before `id`
+        let i = merged.partition_point(|r| *r.end() < id);
+        if merged.get(i).is_some_and(|r| r.contains(&id)) {
+            fresh_ingredients += 1;
+        }
+    }
+
+    Ok(fresh_ingredients)
+}
+
+/// Reference implementation for [`freshness_report`] that checks every range for every ingredient.
+#[doc(hidden)]
+pub fn freshness_report_naive(ranges: &[NumberedRange], ingredient_ids: &[u64]) -> FreshnessReport {
+    let mut ids = ingredient_ids.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let ingredients = ids.iter()
+        .map(|&id| {
+            let mut covering_lines = ranges.iter()
+                .filter(|r| r.range.contains(&id))
+                .map(|r| r.line)
+                .collect::<Vec<_>>();
+            covering_lines.sort_unstable();
+            IngredientReport { id, covering_lines }
+        })
+        .collect();
+    let mut unused_ranges = ranges.iter()
+        .filter(|r| !ids.iter().any(|id| r.range.contains(id)))
+        .cloned()
+        .collect::<Vec<_>>();
+    unused_ranges.sort_unstable_by_key(|r| r.line);
+
+    FreshnessReport { ingredients, unused_ranges }
+}
+
+pub fn parse_range(line: &str) -> RangeInclusive<u64> {
+    let (range_begin, range_end) = line.split_once('-').unwrap();
+    range_begin.parse().unwrap()..=range_end.parse().unwrap()
+}
+
+pub struct Inventory {
+    pub ranges: Vec<NumberedRange>,
+    pub ingredient_ids: Vec<u64>,
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Inventory {
+        let mut lines = input.lines();
+        let ranges = lines.by_ref().take_while(|line| !line.is_empty()).enumerate()
+            .map(|(i, line)| NumberedRange { line: i + 1, range: parse_range(line) })
+            .collect();
+        let ingredient_ids = lines.take_while(|line| !line.is_empty()).map(|line| line.parse().unwrap()).collect();
+        Inventory { ranges, ingredient_ids }
+    }
+
+    /// Counts each fresh id once, however many times it is listed.
+    fn part1(inventory: &Inventory) -> PartAnswer {
+        let fresh_ranges: IntervalSet<u64> = inventory.ranges.iter().map(|r| r.range.clone()).collect();
+        let ids = inventory.ingredient_ids.iter().collect::<BTreeSet<_>>();
+        Ok(ids.into_iter().filter(|id| fresh_ranges.contains(**id)).count().to_string())
+    }
+
+    fn part2(inventory: &Inventory) -> Option<PartAnswer> {
+        let fresh_ranges: IntervalSet<u64> = inventory.ranges.iter().map(|r| r.range.clone()).collect();
+        Some(Ok(fresh_ranges.covered_len().to_string()))
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::rc::Rc;
+use crate::integer::{BigUint, Integer};
+use crate::solver::{input_lines, PartAnswer, PartError, Solver};
+
+/// Which way the digits of a problem's operands are written.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Reading {
+    /// One operand per row, written left to right.
+    Rows,
+    /// One operand per column, most significant digit at the top, read right to left.
+    Columns,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    UnknownOperator,
+    NoOperands,
+    /// The digits of an operand in a row are split by a space, like `12 3`.
+    SplitOperand,
+    /// The result doesn't fit in the integer type.
+    Overflow,
+    /// A subtraction went below zero. Results are unsigned, like the operands.
+    Negative,
+    DivisionByZero,
+    /// The sum of all the results doesn't fit in the integer type.
+    TotalOverflow,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::UnknownOperator => "unknown operator",
+            ErrorKind::NoOperands => "no operands",
+            ErrorKind::SplitOperand => "operand split by a space",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::Negative => "negative result",
+            ErrorKind::DivisionByZero => "division by zero",
+            ErrorKind::TotalOverflow => "overflow adding to the total",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvalError {
+    /// Column of the worksheet where the offending problem starts.
+    pub column: usize,
+    pub operator: String,
+    pub kind: ErrorKind,
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in problem at column {} ('{}')", self.kind, self.column, self.operator)
+    }
+}
+
+type OperatorFn<T> = Rc<dyn Fn(&[T]) -> Result<T, ErrorKind>>;
+
+/// Operators that can appear in a worksheet, by symbol.
+pub struct Operators<T> {
+    table: BTreeMap<String, OperatorFn<T>>,
+}
+
+/// Folds the operands left to right with `f`.
+fn fold<T: Integer>(operands: &[T], f: impl Fn(&T, &T) -> Result<T, ErrorKind>) -> Result<T, ErrorKind> {
+    let (first, rest) = operands.split_first().ok_or(ErrorKind::NoOperands)?;
+    rest.iter().try_fold(first.clone(), |acc, x| f(&acc, x))
+}
+
+impl<T: Integer + 'static> Operators<T> {
+    pub fn empty() -> Operators<T> {
+        Operators { table: BTreeMap::new() }
+    }
+
+    /// `+`, `*`, `-`, `/`, `min` and `max`. Subtraction and division are applied left to right in
+    /// the order the operands are read.
+    ///
+    /// Evaluation is unsigned, as the worksheets only hold natural numbers: a subtraction that
+    /// would go below zero is an [`ErrorKind::Negative`] error rather than a negative result.
+    pub fn standard() -> Operators<T> {
+        let mut ops = Operators::empty();
+        ops.register("+", |xs: &[T]| fold(xs, |a, b| a.checked_add(b).ok_or(ErrorKind::Overflow)));
+        ops.register("*", |xs: &[T]| fold(xs, |a, b| a.checked_mul(b).ok_or(ErrorKind::Overflow)));
+        ops.register("-", |xs: &[T]| fold(xs, |a, b| a.checked_sub(b).ok_or(ErrorKind::Negative)));
+        ops.register("/", |xs: &[T]| fold(xs, |a, b| {
+            if b.is_zero() { Err(ErrorKind::DivisionByZero) } else { Ok(a.div_rem(b).0) }
+        }));
+        ops.register("min", |xs: &[T]| xs.iter().min().cloned().ok_or(ErrorKind::NoOperands));
+        ops.register("max", |xs: &[T]| xs.iter().max().cloned().ok_or(ErrorKind::NoOperands));
+        ops
+    }
+
+    pub fn register(&mut self, symbol: &str, f: impl Fn(&[T]) -> Result<T, ErrorKind> + 'static) {
+        self.table.insert(symbol.to_string(), Rc::new(f));
+    }
+
+    /// Makes `symbol` behave like the already registered `existing`. Returns false if there is no
+    /// such operator.
+    pub fn alias(&mut self, symbol: &str, existing: &str) -> bool {
+        let Some(f) = self.table.get(existing).cloned() else { return false };
+        self.table.insert(symbol.to_string(), f);
+        true
+    }
+
+    /// Registers `symbol` as an operator that folds the operands left to right with `expression`.
+    ///
+    /// The expression is in reverse Polish notation, over `a` for the result so far, `b` for the
+    /// next operand, integer literals and the operators already registered, each applied to two
+    /// values. `a b a b / * -` is the remainder, for example. An expression that is just the
+    /// symbol of a registered operator makes `symbol` an alias of it.
+    pub fn define(&mut self, symbol: &str, expression: &str) -> Result<(), String> {
+        if self.alias(symbol, expression) {
+            return Ok(());
+        }
+        enum Token<T> {
+            Accumulator,
+            Next,
+            Literal(T),
+            Operator(OperatorFn<T>),
+        }
+        let mut tokens = Vec::new();
+        let mut depth = 0usize;
+        for token in expression.split_ascii_whitespace() {
+            tokens.push(match token {
+                "a" => Token::Accumulator,
+                "b" => Token::Next,
+                _ => if let Some(f) = self.get(token) {
+                    if depth < 2 {
+                        return Err(format!("'{token}' needs two values before it"));
+                    }
+                    depth -= 2;
+                    Token::Operator(f.clone())
+                } else if let Ok(value) = token.parse::<T>() {
+                    Token::Literal(value)
+                } else {
+                    let symbols = self.symbols().collect::<Vec<_>>();
+                    return Err(format!("Unknown token '{token}', expected a, b, a number or one of: {}", symbols.join(" ")));
+                },
+            });
+            depth += 1;
+        }
+        if depth != 1 {
+            return Err(format!("'{expression}' must leave exactly one value"));
+        }
+
+        self.register(symbol, move |xs: &[T]| fold(xs, |a, b| {
+            let mut stack = Vec::with_capacity(tokens.len());
+            for token in &tokens {
+                let value = match token {
+                    Token::Accumulator => a.clone(),
+                    Token::Next => b.clone(),
+                    Token::Literal(value) => value.clone(),
+                    Token::Operator(f) => {
+                        let y = stack.pop().unwrap();
+                        let x = stack.pop().unwrap();
+                        f(&[x, y])?
+                    }
+                };
+                stack.push(value);
+            }
+            Ok(stack.pop().unwrap())
+        }));
+        Ok(())
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = &str> {
+        self.table.keys().map(String::as_str)
+    }
+
+    fn get(&self, symbol: &str) -> Option<&OperatorFn<T>> {
+        self.table.get(symbol)
+    }
+}
+
+/// A block of the worksheet between two blank columns.
+#[derive(Clone, Debug)]
+pub struct Problem {
+    /// Column of the worksheet where the block starts.
+    pub column: usize,
+    pub operator: String,
+    /// The operand rows of the block, padded with spaces to the width of the block.
+    cells: Vec<Vec<u8>>,
+}
+
+impl Problem {
+    pub fn width(&self) -> usize {
+        self.cells[0].len()
+    }
+
+    /// The digits of each operand, in the order they are read. A column's digits may be spread over
+    /// rows with gaps between them, but spaces between a row's digits are kept, so that
+    /// [`Problem::evaluate`] can reject them.
+    pub fn operands(&self, reading: Reading) -> Vec<String> {
+        let digits: Vec<Vec<u8>> = match reading {
+            Reading::Rows => self.cells.clone(),
+            Reading::Columns => (0..self.width()).rev()
+                .map(|x| self.cells.iter().map(|row| row[x]).collect())
+                .collect(),
+        };
+        digits.iter()
+            .map(|d| match reading {
+                Reading::Rows => str::from_utf8(d).unwrap().trim().to_string(),
+                Reading::Columns => d.iter().filter(|c| **c != b' ').map(|c| char::from(*c)).collect(),
+            })
+            .filter(|d| !d.is_empty())
+            .collect()
+    }
+
+    pub fn evaluate<T: Integer + 'static>(&self, reading: Reading, operators: &Operators<T>) -> Result<T, EvalError> {
+        let error = |kind| EvalError { column: self.column, operator: self.operator.clone(), kind };
+        let f = operators.get(&self.operator).ok_or_else(|| error(ErrorKind::UnknownOperator))?;
+        // Operands are only digits once split ones are rejected, so they fail to parse only if
+        // they are too large
+        let operands = self.operands(reading).iter()
+            .map(|d| match d.contains(' ') {
+                true => Err(error(ErrorKind::SplitOperand)),
+                false => d.parse::<T>().map_err(|_| error(ErrorKind::Overflow)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        f(&operands).map_err(error)
+    }
+}
+
+pub struct Worksheet {
+    pub problems: Vec<Problem>,
+}
+
+impl Worksheet {
+    /// Parses a worksheet whose last line holds the operators. Problems are separated by columns
+    /// that are blank on every line.
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Worksheet {
+        let width = lines.iter().map(|l| l.as_ref().len()).max().unwrap();
+        let mut rows = lines.iter().map(|line| {
+            let line = line.as_ref();
+            assert!(line.is_ascii());
+            let mut row = line.as_bytes().to_vec();
+            row.resize(width, b' ');
+            row
+        }).collect::<Vec<_>>();
+        let operator_row = rows.pop().expect("Missing operator row");
+        for row in &rows {
+            assert!(row.iter().all(|c| *c == b' ' || c.is_ascii_digit()), "Unexpected character in operand row");
+        }
+
+        let is_blank = |x: usize| operator_row[x] == b' ' && rows.iter().all(|row| row[x] == b' ');
+        let mut problems = Vec::new();
+        let mut x = 0;
+        while x < width {
+            if is_blank(x) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < width && !is_blank(x) {
+                x += 1;
+            }
+
+            let operator = str::from_utf8(&operator_row[start..x]).unwrap().trim();
+            assert!(!operator.is_empty() && !operator.contains(' '), "Problem at column {start} needs exactly one operator");
+            problems.push(Problem {
+                column: start,
+                operator: operator.to_string(),
+                cells: rows.iter().map(|row| row[start..x].to_vec()).collect(),
+            });
+        }
+
+        Worksheet { problems }
+    }
+
+    pub fn evaluate<T: Integer + 'static>(&self, reading: Reading, operators: &Operators<T>) -> Vec<Result<T, EvalError>> {
+        self.problems.iter().map(|p| p.evaluate(reading, operators)).collect()
+    }
+
+    /// Sum of all the problems' results, or the first error.
+    pub fn total<T: Integer + 'static>(&self, reading: Reading, operators: &Operators<T>) -> Result<T, EvalError> {
+        self.problems.iter().try_fold(T::zero(), |total, p| {
+            total.checked_add(&p.evaluate(reading, operators)?).ok_or_else(|| EvalError {
+                column: p.column,
+                operator: p.operator.clone(),
+                kind: ErrorKind::TotalOverflow,
+            })
+        })
+    }
+}
+
+/// Total with the standard operators, retrying with big integers if it overflows `u64`. A problem
+/// that can't be evaluated is the part's error.
+fn total_any_width(worksheet: &Worksheet, reading: Reading) -> PartAnswer {
+    let result = match worksheet.total(reading, &Operators::<u64>::standard()) {
+        Err(e) if matches!(e.kind, ErrorKind::Overflow | ErrorKind::TotalOverflow) => {
+            worksheet.total(reading, &Operators::<BigUint>::standard()).map(|t| t.to_string())
+        }
+        result => result.map(|t| t.to_string()),
+    };
+    result.map_err(|e| PartError::Unsolvable(e.to_string()))
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Worksheet {
+        Worksheet::parse(&input_lines(input).collect::<Vec<_>>())
+    }
+
+    fn part1(worksheet: &Worksheet) -> PartAnswer {
+        total_any_width(worksheet, Reading::Rows)
+    }
+
+    fn part2(worksheet: &Worksheet) -> Option<PartAnswer> {
+        Some(total_any_width(worksheet, Reading::Columns))
+    }
+}
@@ -0,0 +1,244 @@
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, VecDeque};
+use crate::solver::{input_lines, PartAnswer, Solver};
+
+/// What happens to a beam that is deflected past the left or right edge of the manifold.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Boundary {
+    /// The beam leaves the manifold. It still counts as a finished timeline.
+    #[default]
+    Lost,
+    /// The beam re-enters on the opposite edge.
+    Wrap,
+    /// The beam bounces off the wall and stays in the edge column.
+    Reflect,
+}
+
+impl Boundary {
+    pub fn parse(s: &str) -> Option<Boundary> {
+        match s {
+            "lost" => Some(Boundary::Lost),
+            "wrap" => Some(Boundary::Wrap),
+            "reflect" => Some(Boundary::Reflect),
+            _ => None,
+        }
+    }
+}
+
+/// How beams move through the manifold.
+///
+/// A beam deflected by a cell continues from the row below it, in the column it was deflected
+/// to. The cell beside the deflecting one is passed by: in the puzzle it's always empty, so this
+/// gives the same answers, and it keeps every beam moving down so none can loop between
+/// neighbouring cells on one row.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Rules {
+    pub boundary: Boundary,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Empty,
+    /// `^`: Stops the beam and emits new ones to the left and right of it.
+    Splitter,
+    /// `/`: Deflects the beam one column to the left.
+    MirrorLeft,
+    /// `\`: Deflects the beam one column to the right.
+    MirrorRight,
+    /// `#`: Absorbs the beam.
+    Blocker,
+}
+
+impl Cell {
+    fn from_byte(c: u8) -> Option<Cell> {
+        Some(match c {
+            b'.' | b'S' | b'|' => Cell::Empty,
+            b'^' => Cell::Splitter,
+            b'/' => Cell::MirrorLeft,
+            b'\\' => Cell::MirrorRight,
+            b'#' => Cell::Blocker,
+            _ => return None,
+        })
+    }
+}
+
+pub struct Manifold {
+    width: usize,
+    rows: Vec<Vec<Cell>>,
+    start_x: usize,
+}
+
+impl Manifold {
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Manifold {
+        let width = lines[0].as_ref().len();
+        let mut start_x = None;
+        let rows = lines.iter().enumerate().map(|(y, line)| {
+            let line = line.as_ref();
+            assert!(line.is_ascii());
+            assert_eq!(line.len(), width);
+            line.bytes().enumerate().map(|(x, c)| {
+                if c == b'S' {
+                    assert_eq!(y, 0);
+                    start_x = Some(x);
+                }
+                Cell::from_byte(c).unwrap_or_else(|| panic!("Unknown cell '{}'", c as char))
+            }).collect()
+        }).collect();
+
+        Manifold {
+            width,
+            rows,
+            start_x: start_x.expect("No starting position"),
+        }
+    }
+
+    /// Column a beam ends up in when deflected from `x` by `dx`, or `None` if it leaves the manifold.
+    fn deflect(&self, x: usize, dx: isize, rules: &Rules) -> Option<usize> {
+        match x.checked_add_signed(dx).filter(|&x| x < self.width) {
+            Some(x) => Some(x),
+            None => match rules.boundary {
+                Boundary::Lost => None,
+                Boundary::Wrap => Some(x.wrapping_add_signed(dx).wrapping_add(self.width) % self.width),
+                Boundary::Reflect => Some(x),
+            },
+        }
+    }
+
+    /// Columns where the beams continue after hitting `cell` in column `x`, from the row below, see
+    /// [`Rules`].
+    fn outgoing_beams(&self, cell: Cell, x: usize, rules: &Rules) -> [Option<Option<usize>>; 2] {
+        match cell {
+            Cell::Empty => unreachable!(),
+            Cell::Splitter => [Some(self.deflect(x, -1, rules)), Some(self.deflect(x, 1, rules))],
+            Cell::MirrorLeft => [Some(self.deflect(x, -1, rules)), None],
+            Cell::MirrorRight => [Some(self.deflect(x, 1, rules)), None],
+            Cell::Blocker => [None, None],
+        }
+    }
+
+    /// Number of splitters hit by at least one beam.
+    pub fn count_splits(&self, rules: &Rules) -> u64 {
+        let mut beam_queue = VecDeque::new();
+        let mut visited = vec![vec![false; self.width]; self.rows.len()];
+        let mut num_splits = 0;
+
+        beam_queue.push_back((self.start_x, 0));
+
+        while let Some((x, y)) = beam_queue.pop_front() {
+            for (y, row) in self.rows.iter().enumerate().skip(y) {
+                if visited[y][x] {
+                    break;
+                }
+                visited[y][x] = true;
+
+                let cell = row[x];
+                if cell == Cell::Empty {
+                    continue;
+                }
+                if cell == Cell::Splitter {
+                    num_splits += 1;
+                }
+                for new_x in self.outgoing_beams(cell, x, rules).into_iter().flatten().flatten() {
+                    beam_queue.push_back((new_x, y + 1));
+                }
+                break;
+            }
+        }
+
+        num_splits
+    }
+
+    /// Number of distinct paths a single particle can take through the manifold.
+    pub fn count_timelines(&self, rules: &Rules) -> u64 {
+        let mut columns: Vec<BTreeMap<usize, (Cell, OnceCell<u64>)>> = vec![BTreeMap::new(); self.width];
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                if cell != Cell::Empty {
+                    columns[x].insert(y, (cell, OnceCell::new()));
+                }
+            }
+        }
+
+        self.trace_beam(self.start_x, 0, &columns, rules)
+    }
+
+    fn trace_beam(&self, x: usize, y: usize, columns: &[BTreeMap<usize, (Cell, OnceCell<u64>)>], rules: &Rules) -> u64 {
+        if let Some((&y, (cell, num_beams))) = columns[x].range(y..).next() {
+            *num_beams.get_or_init(|| {
+                self.outgoing_beams(*cell, x, rules).into_iter().flatten()
+                    .map(|new_x| match new_x {
+                        Some(new_x) => self.trace_beam(new_x, y + 1, columns, rules),
+                        // Beam left the manifold sideways
+                        None => 1,
+                    })
+                    .sum()
+            })
+        } else {
+            1
+        }
+    }
+
+    /// Reference implementation for [`Manifold::count_splits`] that moves the set of beams down
+    /// one row at a time.
+    #[doc(hidden)]
+    pub fn count_splits_naive(&self, rules: &Rules) -> u64 {
+        let mut hit = vec![vec![false; self.width]; self.rows.len()];
+        let mut beams = vec![false; self.width];
+        beams[self.start_x] = true;
+        for (y, row) in self.rows.iter().enumerate() {
+            let mut next_beams = vec![false; self.width];
+            for x in (0..self.width).filter(|&x| beams[x]) {
+                if row[x] == Cell::Empty {
+                    next_beams[x] = true;
+                    continue;
+                }
+                hit[y][x] = true;
+                for new_x in self.outgoing_beams(row[x], x, rules).into_iter().flatten().flatten() {
+                    next_beams[new_x] = true;
+                }
+            }
+            beams = next_beams;
+        }
+
+        let splitters = self.rows.iter().flatten().zip(hit.iter().flatten());
+        splitters.filter(|&(&cell, &hit)| hit && cell == Cell::Splitter).count() as u64
+    }
+
+    /// Reference implementation for [`Manifold::count_timelines`] that follows every path
+    /// individually. Exponential in the number of splitters.
+    #[doc(hidden)]
+    pub fn count_timelines_naive(&self, rules: &Rules) -> u64 {
+        self.follow_path(self.start_x, 0, rules)
+    }
+
+    fn follow_path(&self, x: usize, y: usize, rules: &Rules) -> u64 {
+        let Some(row) = self.rows.get(y) else { return 1 };
+        if row[x] == Cell::Empty {
+            return self.follow_path(x, y + 1, rules);
+        }
+        self.outgoing_beams(row[x], x, rules).into_iter().flatten()
+            .map(|new_x| match new_x {
+                Some(new_x) => self.follow_path(new_x, y + 1, rules),
+                None => 1,
+            })
+            .sum()
+    }
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = Manifold;
+
+    fn parse(input: &str) -> Manifold {
+        Manifold::parse(&input_lines(input).collect::<Vec<_>>())
+    }
+
+    fn part1(manifold: &Manifold) -> PartAnswer {
+        Ok(manifold.count_splits(&Rules::default()).to_string())
+    }
+
+    fn part2(manifold: &Manifold) -> Option<PartAnswer> {
+        Some(Ok(manifold.count_timelines(&Rules::default()).to_string()))
+    }
+}
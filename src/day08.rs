@@ -0,0 +1,160 @@
+use std::array;
+use std::cmp::Reverse;
+use crate::solver::{input_lines, PartAnswer, Solver};
+
+pub fn sqr_distance(a: &[i64; 3], b: &[i64; 3]) -> i64 {
+    fn sqr(x: i64) -> i64 { x * x }
+    sqr(a[0] - b[0]) + sqr(a[1] - b[1]) + sqr(a[2] - b[2])
+}
+
+fn minmax<T: Ord>(a: T, b: T) -> [T; 2] {
+    if b < a { [b, a] } else { [a, b] }
+}
+
+pub struct UnionFind {
+    memberships: Vec<usize>,
+    set_sizes: Vec<usize>
+}
+
+impl UnionFind {
+    pub fn new(total_items: usize) -> UnionFind {
+        UnionFind {
+            memberships: (0..total_items).collect(),
+            set_sizes: vec![1; total_items],
+        }
+    }
+
+    pub fn memberships(&self) -> &[usize] {
+        &self.memberships
+    }
+
+    /// Size of each set, indexed by set id. Zero for ids that aren't a set.
+    pub fn set_sizes(&self) -> &[usize] {
+        &self.set_sizes
+    }
+
+    /// Returns the id of the set item_id is a member of.
+    pub fn resolve_set(&self, item_id: usize) -> usize {
+        let mut set_id = item_id;
+        while self.memberships[set_id] != set_id {
+            set_id = self.memberships[set_id];
+        }
+        set_id
+    }
+
+    /// Merges the sets of both items together. Returns the id of the resulting set.
+    pub fn merge_items(&mut self, item1: usize, item2: usize) -> usize {
+        let set1 = self.resolve_set(item1);
+        let set2 = self.resolve_set(item2);
+        let [dst_set, src_set] = minmax(set1, set2);
+        if src_set != dst_set {
+            self.memberships[src_set] = dst_set;
+            self.set_sizes[dst_set] += self.set_sizes[src_set];
+            self.set_sizes[src_set] = 0;
+        }
+        dst_set
+    }
+
+    pub fn set_size(&self, item_id: usize) -> usize {
+        self.set_sizes[self.resolve_set(item_id)]
+    }
+
+    pub fn check_integrity(&self) {
+        // Integrity check
+        let mut recounts = vec![0; self.memberships.len()];
+        for i in 0..self.memberships.len() {
+            if self.memberships[i] == i {
+                assert_ne!(self.set_sizes[i], 0);
+            } else {
+                assert_eq!(self.set_sizes[i], 0);
+            }
+            recounts[self.resolve_set(i)] += 1;
+        }
+        assert_eq!(recounts, self.set_sizes);
+    }
+}
+
+pub fn parse_point(line: &str) -> [i64; 3] {
+    let mut it = line.splitn(3, ',').map(|s| s.parse().unwrap());
+    array::from_fn(|_| it.next().unwrap())
+}
+
+/// Squared distances between all pairs of points, in no particular order.
+fn pair_distances(points: &[[i64; 3]]) -> Vec<(i64, (usize, usize))> {
+    let mut distances = Vec::with_capacity(points.len().pow(2) / 2);
+    for (i, pi) in points.iter().enumerate() {
+        for (j, pj) in points.iter().enumerate().skip(i + 1) {
+            assert!(i < j);
+            let d = sqr_distance(pi, pj);
+            distances.push((d, (i, j)));
+        }
+    }
+    distances
+}
+
+/// Connects the `num_connections` closest pairs of points.
+pub fn connect_closest(points: &[[i64; 3]], num_connections: usize) -> UnionFind {
+    let mut distances = pair_distances(points);
+    let mut sets = UnionFind::new(points.len());
+    let head_len = distances.len().min(num_connections);
+    if head_len == 0 {
+        return sets;
+    }
+    distances.select_nth_unstable_by_key(head_len - 1, |(d, _)| *d);
+    let head = &mut distances[..head_len];
+    head.sort_unstable_by_key(|(_, ij)| *ij);
+
+    for &(_, (i, j)) in &*head {
+        sets.merge_items(i, j);
+    }
+    sets.check_integrity();
+    sets
+}
+
+/// Product of the sizes of the 3 largest sets.
+pub fn largest_sets_product(sets: &UnionFind) -> usize {
+    let mut set_counts = sets.set_sizes().to_vec();
+    let k = set_counts.len().min(3);
+    if k == 0 {
+        return 0;
+    }
+    set_counts.select_nth_unstable_by_key(k - 1, |&x| Reverse(x));
+    set_counts[..k].iter().copied().filter(|x| *x != 0).product()
+}
+
+/// The pair of points whose connection, going from the closest pair up, joins all the points
+/// into a single set.
+pub fn final_connection(points: &[[i64; 3]]) -> Option<(usize, usize)> {
+    let mut distances = pair_distances(points);
+    distances.sort_unstable_by_key(|(d, _)| *d);
+    let mut sets = UnionFind::new(points.len());
+    let mut last_pair = None;
+    for &(_, (i, j)) in &distances {
+        let result_set = sets.merge_items(i, j);
+        if sets.set_size(result_set) == points.len() {
+            last_pair = Some((i, j));
+            break;
+        }
+    }
+    sets.check_integrity();
+    last_pair
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Vec<[i64; 3]>;
+
+    fn parse(input: &str) -> Vec<[i64; 3]> {
+        input_lines(input).map(parse_point).collect()
+    }
+
+    fn part1(points: &Vec<[i64; 3]>) -> PartAnswer {
+        Ok(largest_sets_product(&connect_closest(points, 1000)).to_string())
+    }
+
+    fn part2(points: &Vec<[i64; 3]>) -> Option<PartAnswer> {
+        let (i, j) = final_connection(points).expect("Points never form a single circuit");
+        Some(Ok((points[i][0] * points[j][0]).to_string()))
+    }
+}
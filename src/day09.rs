@@ -0,0 +1,172 @@
+use std::array;
+use crate::solver::{input_lines, PartAnswer, Solver};
+
+pub fn calculate_area(a: &[u64; 2], b: &[u64; 2]) -> u64 {
+    let width = a[0].abs_diff(b[0]) + 1;
+    let height = a[1].abs_diff(b[1]) + 1;
+    width * height
+}
+
+pub fn minmax<T: Ord>(a: T, b: T) -> [T; 2] {
+    if b < a { [b, a] } else { [a, b] }
+}
+
+/// Largest rectangle with red tiles in two opposite corners.
+pub fn largest_rectangle(points: &[[u64; 2]]) -> u64 {
+    let mut max_area = 0;
+    for (i, pi) in points.iter().enumerate() {
+        for (_j, pj) in points.iter().enumerate().skip(i + 1) {
+            let area = calculate_area(pi, pj);
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+    max_area
+}
+
+/// Tiles on or inside the loop, on a grid compressed to the coordinates where that can change.
+///
+/// Every point's coordinate and the one after it start a new column or row, so each column or
+/// row is either a single tile on the line of an edge, or a run of tiles between edges.
+struct CompressedLoop {
+    xs: Vec<u64>,
+    ys: Vec<u64>,
+    /// Number of compressed cells outside the loop above and to the left of each cell, exclusive,
+    /// with an extra row and column.
+    outside_before: Vec<Vec<u32>>,
+}
+
+impl CompressedLoop {
+    fn new(points: &[[u64; 2]]) -> CompressedLoop {
+        let breakpoints = |axis: usize| {
+            let mut v = points.iter().flat_map(|p| [p[axis], p[axis] + 1]).collect::<Vec<_>>();
+            v.sort_unstable();
+            v.dedup();
+            v
+        };
+        let (xs, ys) = (breakpoints(0), breakpoints(1));
+        let column = |x: u64| xs.binary_search(&x).unwrap();
+        let edges = || points.iter().zip(points.iter().cycle().skip(1));
+
+        let mut outside_before = vec![vec![0; xs.len() + 1]; ys.len() + 1];
+        for (row, &y) in ys.iter().enumerate() {
+            // Same rules as `tile_in_loop`, for all columns of the row at once
+            let mut on_loop = vec![false; xs.len()];
+            let mut crossings = vec![false; xs.len()];
+            for (a, b) in edges() {
+                let [min_x, max_x] = minmax(a[0], b[0]);
+                let [min_y, max_y] = minmax(a[1], b[1]);
+                if (min_y..=max_y).contains(&y) {
+                    on_loop[column(min_x)..=column(max_x)].fill(true);
+                }
+                if a[0] == b[0] && (min_y..max_y).contains(&y) {
+                    crossings[column(a[0])] ^= true;
+                }
+            }
+            let mut inside = false;
+            let mut outside_in_row = vec![0; xs.len()];
+            for col in (0..xs.len()).rev() {
+                outside_in_row[col] = u32::from(!on_loop[col] && !inside);
+                inside ^= crossings[col];
+            }
+            for col in 0..xs.len() {
+                outside_before[row + 1][col + 1] = outside_before[row][col + 1] + outside_before[row + 1][col]
+                    - outside_before[row][col] + outside_in_row[col];
+            }
+        }
+
+        CompressedLoop { xs, ys, outside_before }
+    }
+
+    /// Whether every tile from `a` to `b` inclusive is on or inside the loop. Both must be points
+    /// of the loop, with `a` above and to the left of `b`.
+    fn contains_rect(&self, a: [u64; 2], b: [u64; 2]) -> bool {
+        let [x0, x1] = [a[0], b[0]].map(|x| self.xs.binary_search(&x).unwrap());
+        let [y0, y1] = [a[1], b[1]].map(|y| self.ys.binary_search(&y).unwrap());
+        let t = &self.outside_before;
+        t[y1 + 1][x1 + 1] + t[y0][x0] == t[y0][x1 + 1] + t[y1 + 1][x0]
+    }
+}
+
+/// Largest rectangle with red tiles in two opposite corners that lies entirely inside the loop
+/// formed by `points`.
+pub fn largest_inner_rectangle(points: &[[u64; 2]]) -> u64 {
+    let compressed = CompressedLoop::new(points);
+    let mut max_area = 0;
+    for (i, pi) in points.iter().copied().enumerate() {
+        for pj in points[i + 1..].iter().copied() {
+            let area = calculate_area(&pi, &pj);
+            if area <= max_area {
+                continue;
+            }
+
+            let [min_x, max_x] = minmax(pi[0], pj[0]);
+            let [min_y, max_y] = minmax(pi[1], pj[1]);
+            if compressed.contains_rect([min_x, min_y], [max_x, max_y]) {
+                max_area = area;
+            }
+        }
+    }
+    max_area
+}
+
+/// Whether tile `p` is on the loop or enclosed by it.
+fn tile_in_loop(points: &[[u64; 2]], [x, y]: [u64; 2]) -> bool {
+    let mut inside = false;
+    let mut prev_p = points.last().unwrap();
+    for p in points {
+        let [min_x, max_x] = minmax(prev_p[0], p[0]);
+        let [min_y, max_y] = minmax(prev_p[1], p[1]);
+        if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+            return true;
+        }
+        // Count vertical edges crossed by a ray going right, half-open so corners count once
+        if p[0] == prev_p[0] && p[0] > x && (min_y..max_y).contains(&y) {
+            inside = !inside;
+        }
+        prev_p = p;
+    }
+    inside
+}
+
+/// Reference implementation for [`largest_inner_rectangle`] that checks every tile of every
+/// candidate rectangle. Only usable with small coordinates.
+#[doc(hidden)]
+pub fn largest_inner_rectangle_naive(points: &[[u64; 2]]) -> u64 {
+    let mut max_area = 0;
+    for (i, pi) in points.iter().enumerate() {
+        for pj in &points[i + 1..] {
+            let [min_x, max_x] = minmax(pi[0], pj[0]);
+            let [min_y, max_y] = minmax(pi[1], pj[1]);
+            let inside = (min_y..=max_y).all(|y| (min_x..=max_x).all(|x| tile_in_loop(points, [x, y])));
+            if inside {
+                max_area = max_area.max(calculate_area(pi, pj));
+            }
+        }
+    }
+    max_area
+}
+
+pub fn parse_point(line: &str) -> [u64; 2] {
+    let mut it = line.splitn(2, ',').map(|s| s.parse().unwrap());
+    array::from_fn(|_| it.next().unwrap())
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<[u64; 2]>;
+
+    fn parse(input: &str) -> Vec<[u64; 2]> {
+        input_lines(input).map(parse_point).collect()
+    }
+
+    fn part1(points: &Vec<[u64; 2]>) -> PartAnswer {
+        Ok(largest_rectangle(points).to_string())
+    }
+
+    fn part2(points: &Vec<[u64; 2]>) -> Option<PartAnswer> {
+        Some(Ok(largest_inner_rectangle(points).to_string()))
+    }
+}
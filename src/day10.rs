@@ -0,0 +1,404 @@
+use std::iter;
+use microlp::{ComparisonOp, OptimizationDirection, Problem};
+use crate::cancel::{Cancel, Cancelled};
+use crate::log::{self, Level};
+use crate::progress::Progress;
+use crate::solver::{input_lines, PartAnswer, PartError, Solver};
+use crate::{debug, span, trace};
+
+trait BitSetOps {
+    fn bit(&self, i: usize) -> bool;
+    fn set_bit(&mut self, i: usize);
+    fn clear_bit(&mut self, i: usize);
+
+    fn iter_bits(&self) -> impl Iterator<Item=usize>;
+}
+
+impl BitSetOps for u32 {
+    fn bit(&self, i: usize) -> bool {
+        *self >> i & 1 != 0
+    }
+
+    fn set_bit(&mut self, i: usize) {
+        *self |= 1 << i;
+    }
+
+    fn clear_bit(&mut self, i: usize) {
+        *self &= !(1 << i);
+    }
+
+    fn iter_bits(&self) -> impl Iterator<Item=usize> {
+        let mut x = *self;
+        iter::from_fn(move || {
+            if x == 0 {
+                return None;
+            }
+            let next = x.trailing_zeros();
+            // Clear lowest bit
+            x &= x - 1;
+            Some(next as usize)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Machine {
+    pub num_lights: u32,
+    // Lights are represented as bitfields
+    pub desired_lights: u32,
+    pub buttons: Vec<u32>,
+    pub joltages: Vec<u32>,
+}
+
+pub fn parse_machine(line: &str) -> Option<Machine> {
+    let mut it = line.split(' ').peekable();
+
+    let lights_str = it.next()?.strip_prefix('[')?.strip_suffix(']')?;
+    let num_lights = u32::try_from(lights_str.chars().count()).ok()?;
+    let desired_lights = lights_str.chars()
+        .enumerate()
+        .filter_map(|(i, c)| (c == '#').then_some(i))
+        .fold(0u32, |val, bit_i| val | 1 << bit_i);
+
+    let mut buttons = Vec::new();
+    while it.peek()?.starts_with('(') {
+        let button_str = it.next()?.strip_prefix('(')?.strip_suffix(')')?;
+        let button = button_str.split(',')
+            .map(|s| s.parse::<u32>().unwrap())
+            .inspect(|i| assert!(*i < num_lights))
+            .fold(0u32, |val, bit_i| val | 1 << bit_i);
+        buttons.push(button);
+    }
+
+    let joltage_str = it.next()?.strip_prefix('{')?.strip_suffix('}')?;
+    let joltages = joltage_str.split(',')
+        .map(|s| s.parse::<u32>().unwrap())
+        .collect::<Vec<_>>();
+
+    Some(Machine {
+        num_lights,
+        desired_lights,
+        buttons,
+        joltages,
+    })
+}
+
+/// Fewest button presses that turn on exactly the desired lights, trying every combination.
+pub fn min_presses_lights(machine: &Machine) -> u32 {
+    let mut best = u32::MAX;
+    for setting in 0..(1u32 << machine.buttons.len()) {
+        let mut state = 0u32;
+        for (i, toggled) in machine.buttons.iter().copied().enumerate() {
+            if setting & 1 << i != 0 {
+                state ^= toggled;
+            }
+        }
+        if state == machine.desired_lights {
+            best = best.min(setting.count_ones());
+        }
+    }
+    assert_ne!(best, u32::MAX);
+    best
+}
+
+/// Fewest button presses that reach the joltage requirements, as an integer linear program.
+pub fn min_presses_joltage(machine: &Machine) -> u32 {
+    let mut p = Problem::new(OptimizationDirection::Minimize);
+    let vars = (0..machine.buttons.len())
+        .map(|_| p.add_integer_var(1.0, (0, i32::MAX)))
+        .collect::<Vec<_>>();
+    for (jolt_i, joltage) in machine.joltages.iter().copied().enumerate() {
+        let c = machine.buttons.iter().zip(&vars)
+            .filter_map(|(b, var)| b.bit(jolt_i).then_some((*var, 1.0f64)));
+        p.add_constraint(c, ComparisonOp::Eq, f64::from(joltage));
+    }
+
+    let solution = p.solve().unwrap();
+    let result = solution.objective();
+    assert!((result - result.round()).abs() <= 0.0001);
+    result.round() as u32
+}
+
+/// Sum of `min_presses` over all machines. A cancelled machine is reported by its number, with
+/// the sum of the ones before as the best bound unless its own search found one.
+fn sum_over_machines(machines: &[Machine], cancel: &Cancel, min_presses: impl Fn(&Machine, &Cancel) -> Result<u32, Cancelled>) -> Result<u32, Cancelled> {
+    let mut progress = Progress::new("machine", machines.len());
+    let mut sum = 0;
+    for (i, m) in machines.iter().enumerate() {
+        sum += min_presses(m, cancel).map_err(|c| c.at_record("machine", i).with_best(format!("{sum} from the machines before")))?;
+        progress.advance();
+    }
+    Ok(sum)
+}
+
+/// Sum of [`min_presses_joltage`] over all machines. Checks `cancel` between machines.
+pub fn total_presses_joltage(machines: &[Machine], cancel: &Cancel) -> Result<u32, Cancelled> {
+    sum_over_machines(machines, cancel, |m, cancel| {
+        cancel.check()?;
+        Ok(min_presses_joltage(m))
+    })
+}
+
+struct BacktrackingState {
+    mtx: Vec<Vec<f64>>,
+    mtx_free_vars: u32, // bitset
+    mtx_dependency_vars: Vec<u32>, // bitset
+
+    /// Indexed by variable, which equations it affects
+    columns: Vec<u32>, // bitset
+    /// Indexed by equation, set of which variables affect it
+    lines: Vec<u32>, // bitset
+
+    remaining_constant: Vec<u32>,
+    free_vars: u32, // bitset
+    fixed_values: Vec<u32>,
+
+    current_sum: u32,
+    best_sum: u32,
+    cancel: Cancel,
+}
+
+const ZERO_EPSILON: f64 = 0.000001;
+
+fn log_matrix(mtx: &[Vec<f64>]) {
+    for r in mtx {
+        let coefficients = r[..r.len() - 1].iter().map(|x| format!(" {x:4.1}")).collect::<String>();
+        debug!("[{coefficients} |{:5.1}]", r.last().unwrap());
+    }
+}
+
+fn gauss_jordan_reduction(mtx: &mut [Vec<f64>]) -> u32 {
+    let num_cols = mtx[0].len();
+    for r in &*mtx {
+        assert_eq!(r.len(), num_cols);
+    }
+    let mut free_variables = (1 << (num_cols - 1)) - 1;
+
+    let mut pivot = 0;
+    for c in 0..mtx[0].len() - 1 {
+        let new_pivot = mtx.iter()
+            .map(|r| r[c])
+            .enumerate()
+            .skip(pivot)
+            .filter(|(_, x)| x.abs() >= ZERO_EPSILON)
+            .min_by(|(_, x), (_, y)| (x.abs() - 1.0).abs().total_cmp(&(y.abs() - 1.0).abs()));
+        if let Some((new_pivot, scale)) = new_pivot {
+            mtx.swap(new_pivot, pivot);
+            mtx[pivot].iter_mut().for_each(|x| *x /= scale);
+
+            for r in 0..mtx.len() {
+                let Ok([row, p]) = mtx.get_disjoint_mut([r, pivot]) else { continue };
+                let scale = row[c];
+                if scale.abs() < ZERO_EPSILON {
+                    continue;
+                }
+                row.iter_mut().zip(p).for_each(|(x, p)| *x -= *p * scale);
+            }
+            free_variables.clear_bit(c);
+            pivot += 1;
+        }
+    }
+
+    free_variables
+}
+
+fn expand_bitmatrix(bitlines: &[u32], width: usize, constants: &[u32]) -> Vec<Vec<f64>> {
+    bitlines.iter().zip(constants).map(|(bits, constant)| {
+        (0..width).map(|i| if bits.bit(i) { 1.0 } else { 0.0 })
+            .chain(iter::once(f64::from(*constant))).collect()
+    }).collect()
+}
+
+impl BacktrackingState {
+    fn with_constrained_var<T>(&mut self, var_i: usize, value: u32, f: impl FnOnce(&mut Self) -> T) -> Option<T> {
+        assert!(self.free_vars.bit(var_i));
+        let span = span!(Level::Trace, "Attempt fix of var {var_i} to {value}");
+
+        if self.current_sum + value >= self.best_sum {
+            trace!("< {} worse than best sum ({})", self.current_sum + value, self.best_sum);
+            return None;
+        }
+
+        // Reduce constants in affected equations
+        for equ_i in self.columns[var_i].iter_bits() {
+            if value > self.remaining_constant[equ_i] {
+                trace!("< Value ({value}) exceeds constant {equ_i} ({})", self.remaining_constant[equ_i]);
+                // Impossible state, revert changes and backtrack
+                let traversed_set = self.columns[var_i] & ((1 << equ_i) - 1);
+                for equ_j in traversed_set.iter_bits() {
+                    self.remaining_constant[equ_j] += value;
+                }
+                return None;
+            }
+            self.remaining_constant[equ_i] -= value;
+        }
+        self.current_sum += value;
+        self.free_vars.clear_bit(var_i);
+        self.fixed_values[var_i] = value;
+
+        let result = f(self);
+
+        drop(span);
+        trace!("Backtracking fixing var {var_i} to {value}");
+        self.fixed_values[var_i] = u32::MAX;
+        self.free_vars.set_bit(var_i);
+        self.current_sum -= value;
+        for equ_i in self.columns[var_i].iter_bits() {
+            self.remaining_constant[equ_i] += value;
+        }
+        Some(result)
+    }
+
+    fn log_state(&self) {
+        if !log::enabled(module_path!(), Level::Trace) {
+            return;
+        }
+        let vars_s = (0..self.columns.len())
+            .map(|i| if self.free_vars.bit(i) { format!("{i}: _") } else { format!("{i}: {}", self.fixed_values[i]) })
+            .collect::<Vec<_>>().join(", ");
+        let remaining_s = self.remaining_constant.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(" ");
+        trace!("vars: ({vars_s}); remaining: ({remaining_s})");
+    }
+
+    fn calculate_any_constrained_var(&mut self) -> Result<(), Cancelled> {
+        if let Some((eqn_i, dep_vars)) = self.mtx_dependency_vars.iter().enumerate().find(|(_, r)| (*r & self.free_vars).count_ones() == 1) {
+            let target_var = (dep_vars & self.free_vars).iter_bits().next().unwrap();
+            let eqn = &self.mtx[eqn_i];
+            let mut value = *eqn.last().unwrap();
+            for var_i in (dep_vars & !self.free_vars).iter_bits() {
+                value -= f64::from(self.fixed_values[var_i]) * eqn[var_i];
+            }
+            if value.round() < 0.0 {
+                trace!("< Calculated x_{target_var} less than 0: {value}");
+                return Ok(());
+            }
+            if (value - value.round()).abs() > ZERO_EPSILON {
+                trace!("< Calculated x_{target_var} has non-integer solution: {value}");
+                return Ok(());
+            }
+            self.with_constrained_var(target_var, value.round() as u32, Self::calculate_any_constrained_var).unwrap_or(Ok(()))
+        } else {
+            self.search_solution()
+        }
+    }
+
+    fn search_solution(&mut self) -> Result<(), Cancelled> {
+        self.log_state();
+
+        if self.free_vars == 0 {
+            // Backtracking shouldn't have allowed getting here if the sum was worse
+            assert!(self.current_sum < self.best_sum);
+            if self.remaining_constant.iter().all(|x| *x == 0) {
+                trace!("-- New best: {} -> {}", self.best_sum, self.current_sum);
+                self.best_sum = self.current_sum;
+            } else {
+                trace!("-- Bad solution");
+            }
+            return Ok(());
+        }
+
+        let var_i = (self.free_vars & self.mtx_free_vars).iter_bits().next().unwrap();
+        let max_value = self.columns[var_i].iter_bits().map(|x| self.remaining_constant[x]).min().unwrap();
+        for value in 0..=max_value {
+            self.cancel.check().map_err(|c| match self.best_sum {
+                u32::MAX => c,
+                best_sum => c.with_best(best_sum),
+            })?;
+            if value != 0 {
+                self.log_state();
+            }
+            self.with_constrained_var(var_i, value, Self::calculate_any_constrained_var).unwrap_or(Ok(()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Fewest button presses that reach the joltage requirements, found by reducing the system of
+/// equations and backtracking over the values of its free variables. Checks `cancel` between
+/// values, reporting the best sum found so far if it trips.
+pub fn min_presses_joltage_search(m: &Machine, cancel: &Cancel) -> Result<u32, Cancelled> {
+    assert!(m.buttons.len() < 32);
+    cancel.check()?;
+    // buttons == variables
+    // joltages == constraints
+
+    // Transpose bit matrix
+    let affecting_buttons = (0..m.joltages.len()).map(|jolt_i| {
+        m.buttons.iter()
+            .enumerate()
+            .filter_map(|(var_i, b)| b.bit(jolt_i).then_some(var_i))
+            .fold(0u32, |val, bit_i| val | 1 << bit_i)
+    }).collect::<Vec<_>>();
+
+    // Pre-solve matrix to guide integer solution search
+    let mut mtx = expand_bitmatrix(&affecting_buttons, m.buttons.len(), &m.joltages);
+    let mtx_free_vars = gauss_jordan_reduction(&mut mtx);
+    // Remove useless matrix lines
+    while mtx.pop_if(|r| r.iter().all(|x| *x < ZERO_EPSILON)).is_some() {}
+    // Calculate constrained variable dependencies
+    let mtx_dependency_vars = mtx.iter().map(|r| {
+        r[..r.len() - 1].iter().enumerate()
+            .filter(|(_, x)| x.abs() >= ZERO_EPSILON)
+            .fold(0u32, |vars, (c, _)| vars | (1 << c))
+    }).collect::<Vec<_>>();
+
+    let mut state = BacktrackingState {
+        mtx,
+        mtx_free_vars,
+        mtx_dependency_vars,
+        columns: m.buttons.clone(),
+        lines: affecting_buttons,
+        remaining_constant: m.joltages.clone(),
+        free_vars: (1u32 << m.buttons.len()) - 1,
+        fixed_values: vec![u32::MAX; m.buttons.len()],
+        current_sum: 0,
+        best_sum: u32::MAX,
+        cancel: cancel.clone(),
+    };
+
+    debug!("Matrix representation:");
+    for (line, constant) in state.lines.iter().zip(&state.remaining_constant) {
+        let line_s = (0..state.columns.len()).map(|i| if line.bit(i) { "1" } else { "0" }).collect::<Vec<_>>().join(" ");
+        debug!("[{line_s} | {constant}]");
+    }
+    debug!("Reduced:");
+    log_matrix(&state.mtx);
+
+    let _span = span!(Level::Debug, "Searching");
+    state.calculate_any_constrained_var()?;
+    debug!("Best sum: {}", state.best_sum);
+    assert_ne!(state.best_sum, u32::MAX, "No solution");
+    Ok(state.best_sum)
+}
+
+/// Sum of [`min_presses_joltage_search`] over all machines, stopping within a machine's search if
+/// `cancel` trips.
+pub fn total_presses_joltage_search(machines: &[Machine], cancel: &Cancel) -> Result<u32, Cancelled> {
+    sum_over_machines(machines, cancel, min_presses_joltage_search)
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Vec<Machine> {
+        input_lines(input).map(|line| parse_machine(line).unwrap()).collect()
+    }
+
+    fn part1(machines: &Vec<Machine>) -> PartAnswer {
+        Ok(machines.iter().map(min_presses_lights).sum::<u32>().to_string())
+    }
+
+    fn part2(machines: &Vec<Machine>) -> Option<PartAnswer> {
+        Some(total_presses_joltage(machines, &Cancel::current()).map(|sum| sum.to_string()).map_err(PartError::from))
+    }
+
+    fn part1_records(machines: &Vec<Machine>) -> Option<Vec<String>> {
+        Some(machines.iter().map(|m| min_presses_lights(m).to_string()).collect())
+    }
+
+    fn part2_records(machines: &Vec<Machine>) -> Option<Vec<String>> {
+        Some(machines.iter().map(|m| min_presses_joltage(m).to_string()).collect())
+    }
+}
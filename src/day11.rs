@@ -0,0 +1,207 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use crate::cancel::{Cancel, Cancelled};
+use crate::log::Level;
+use crate::solver::{input_lines, PartAnswer, PartError, Solver};
+use crate::{debug, span, trace};
+
+pub type NodeId = [u8; 3];
+
+fn node_name(id: &NodeId) -> &str {
+    str::from_utf8(id).unwrap()
+}
+
+fn convert_node_id(s: &str) -> NodeId {
+    assert_eq!(s.len(), 3);
+    assert!(s.is_ascii());
+    s.as_bytes().try_into().unwrap()
+}
+
+/// Outgoing connections of each device. `out` has none and no entry of its own.
+pub type Connections = HashMap<NodeId, Vec<NodeId>>;
+
+pub fn parse_connections<'a>(lines: impl IntoIterator<Item = &'a str>) -> Connections {
+    lines.into_iter().map(|line| {
+        let mut it = line.split_ascii_whitespace();
+        let src_node = it.next().unwrap().strip_suffix(':').unwrap();
+        (convert_node_id(src_node), it.map(convert_node_id).collect())
+    }).collect()
+}
+
+/// Why the paths couldn't be counted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PathError {
+    /// The connections lead from this device back to itself.
+    Cycle(NodeId),
+    Cancelled(Cancelled),
+}
+
+impl From<Cancelled> for PathError {
+    fn from(cancelled: Cancelled) -> Self {
+        PathError::Cancelled(cancelled)
+    }
+}
+
+impl From<PathError> for PartError {
+    fn from(e: PathError) -> Self {
+        match e {
+            PathError::Cycle(_) => PartError::Unsolvable(e.to_string()),
+            PathError::Cancelled(cancelled) => PartError::Cancelled(cancelled),
+        }
+    }
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Cycle(id) => write!(f, "cycle through {}", String::from_utf8_lossy(id)),
+            PathError::Cancelled(cancelled) => cancelled.fmt(f),
+        }
+    }
+}
+
+fn count_paths_from(counter: &mut u64, connections: &Connections, on_path: &mut Vec<NodeId>, node_id: &NodeId, cancel: &Cancel) -> Result<(), PathError> {
+    if on_path.contains(node_id) {
+        return Err(PathError::Cycle(*node_id));
+    }
+    cancel.check().map_err(|c| c.with_best(format!("at least {counter} paths")))?;
+    on_path.push(*node_id);
+    for id in connections[node_id].iter() {
+        if id == b"out" {
+            *counter += 1;
+        } else {
+            count_paths_from(counter, connections, on_path, id, cancel)?;
+        }
+    }
+    on_path.pop();
+    Ok(())
+}
+
+/// Number of paths from `start` to `out`, following every one of them. Checks `cancel` at every
+/// node, reporting the paths counted so far if it trips.
+pub fn count_paths(connections: &Connections, start: &NodeId, cancel: &Cancel) -> Result<u64, PathError> {
+    let mut path_count = 0;
+    count_paths_from(&mut path_count, connections, &mut Vec::new(), start, cancel)?;
+    Ok(path_count)
+}
+
+struct Node {
+    visited: Cell<bool>,
+    reaches_fft: Cell<bool>,
+    reaches_dac: Cell<bool>,
+    path_count: Cell<Option<u64>>,
+    outs: Vec<NodeId>,
+}
+
+impl Node {
+    fn new(outs: Vec<NodeId>) -> Node {
+        Node {
+            visited: Cell::new(false),
+            reaches_fft: Cell::new(false),
+            reaches_dac: Cell::new(false),
+            path_count: Cell::new(None),
+            outs,
+        }
+    }
+}
+
+struct State<'a> {
+    path_len: u32,
+    fft_node: &'a Node,
+    dac_node: &'a Node,
+    cancel: &'a Cancel,
+}
+
+fn recurse(state: &mut State, connections: &HashMap<NodeId, Node>, node_id: &NodeId) -> Result<u64, PathError> {
+    let node = &connections[node_id];
+    if !node.reaches_dac.get() && !state.dac_node.visited.get() {
+        return Ok(0);
+    }
+    if !node.reaches_fft.get() && !state.fft_node.visited.get() {
+        return Ok(0);
+    }
+    if let Some(path_count) = node.path_count.get() {
+        return Ok(path_count);
+    }
+    state.cancel.check()?;
+    let _span = span!(Level::Trace, "Visiting {}", node_name(node_id));
+    if node.visited.replace(true) {
+        return Err(PathError::Cycle(*node_id));
+    }
+    state.path_len += 1;
+    let mut path_count = 0;
+    for id in node.outs.iter() {
+        if id == b"out" {
+            // A node that still has to reach fft or dac can lead straight to out as well
+            path_count += u64::from(state.fft_node.visited.get() && state.dac_node.visited.get());
+        } else {
+            path_count += recurse(state, connections, id)?;
+        }
+    }
+    node.visited.set(false);
+    state.path_len -= 1;
+
+    node.path_count.set(Some(path_count));
+    trace!("{} paths from {}", path_count, node_name(node_id));
+    Ok(path_count)
+}
+
+/// Tags `node_id` and every node leading to it with `f`, stopping at nodes that were already tagged.
+fn tag_node_reachability(nodes: &HashMap<NodeId, Node>, node_ins: &HashMap<NodeId, Vec<NodeId>>, tag: &NodeId, node_id: &NodeId, f: fn(&Node) -> bool) {
+    let node = &nodes[node_id];
+    if f(node) {
+        trace!("{} <= {}", node_name(tag), node_name(node_id));
+        for id in &node_ins[node_id] {
+            tag_node_reachability(nodes, node_ins, tag, id, f);
+        }
+    }
+}
+
+/// Number of paths from `svr` to `out` that go through both `fft` and `dac`. Checks `cancel` at
+/// every node it hasn't counted the paths from yet.
+pub fn count_paths_via_fft_dac(connections: &Connections, cancel: &Cancel) -> Result<u64, PathError> {
+    let mut nodes: HashMap<NodeId, Node> = connections.iter().map(|(id, outs)| (*id, Node::new(outs.clone()))).collect();
+    nodes.insert(*b"out", Node::new(Vec::new()));
+
+    let mut node_ins: HashMap<NodeId, Vec<NodeId>> = nodes.keys().map(|k| (*k, Vec::new())).collect::<HashMap<_, _>>();
+    for (id, node) in &nodes {
+        for out_node in node.outs.iter() {
+            node_ins.get_mut(out_node).unwrap().push(*id);
+        }
+    }
+
+    tag_node_reachability(&nodes, &node_ins, b"fft", b"fft", |node| !node.reaches_fft.replace(true));
+    tag_node_reachability(&nodes, &node_ins, b"dac", b"dac", |node| !node.reaches_dac.replace(true));
+    debug!(
+        "{} nodes reach fft, {} reach dac",
+        nodes.values().filter(|n| n.reaches_fft.get()).count(),
+        nodes.values().filter(|n| n.reaches_dac.get()).count(),
+    );
+
+    let mut state = State {
+        path_len: 0,
+        fft_node: &nodes[b"fft"],
+        dac_node: &nodes[b"dac"],
+        cancel,
+    };
+    recurse(&mut state, &nodes, b"svr")
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Connections;
+
+    fn parse(input: &str) -> Connections {
+        parse_connections(input_lines(input))
+    }
+
+    fn part1(connections: &Connections) -> PartAnswer {
+        Ok(count_paths(connections, b"you", &Cancel::current())?.to_string())
+    }
+
+    fn part2(connections: &Connections) -> Option<PartAnswer> {
+        Some(count_paths_via_fft_dac(connections, &Cancel::current()).map(|count| count.to_string()).map_err(PartError::from))
+    }
+}
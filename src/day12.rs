@@ -0,0 +1,179 @@
+use std::array;
+use std::fmt::{Debug, Formatter, Write};
+use crate::cancel::{Cancel, Cancelled};
+use crate::progress::Progress;
+use crate::solver::{PartAnswer, Solver};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Shape([[bool; 3]; 3]);
+
+impl Shape {
+    fn at(&self, x: usize, y: usize) -> bool {
+        self.0[y][x]
+    }
+    fn rotate_cw(&self) -> Shape {
+        let s = self;
+        Shape([
+            [s.at(0, 2), s.at(0, 1), s.at(0, 0)],
+            [s.at(1, 2), s.at(1, 1), s.at(1, 0)],
+            [s.at(2, 2), s.at(2, 1), s.at(2, 0)],
+        ])
+    }
+
+    fn flipped(&self) -> Shape {
+        let s = self;
+        Shape([
+            [s.at(2, 0), s.at(1, 0), s.at(0, 0)],
+            [s.at(2, 1), s.at(1, 1), s.at(0, 1)],
+            [s.at(2, 2), s.at(1, 2), s.at(0, 2)],
+        ])
+    }
+}
+
+impl Debug for Shape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Shape(")?;
+        for (y, row) in self.0.iter().enumerate() {
+            for c in row.iter() {
+                f.write_char(if *c { '#' } else { '.' })?;
+            }
+            if y != 2 {
+                f.write_str(", ")?;
+            }
+        }
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ShapeInfo {
+    pub rotations: [Option<Shape>; 4],
+    pub flipped_rots: [Option<Shape>; 4],
+    pub area: u32,
+}
+
+#[derive(Debug)]
+pub struct Region {
+    pub dim: [u32; 2],
+    pub shape_counts: Vec<u32>,
+}
+
+impl Region {
+    pub fn area(&self) -> u32 {
+        self.dim[0] * self.dim[1]
+    }
+
+    /// Total area of all the presents that have to fit in the region.
+    pub fn shape_area(&self, shapes: &[ShapeInfo]) -> u32 {
+        self.shape_counts.iter().zip(shapes).map(|(count, info)| count * info.area).sum()
+    }
+
+    /// Whether the presents could fit going by area alone.
+    pub fn fits_by_area(&self, shapes: &[ShapeInfo]) -> bool {
+        self.shape_area(shapes) <= self.area()
+    }
+}
+
+fn create_rotations(mut shape: Shape) -> ([Option<Shape>; 4], [Option<Shape>; 4]) {
+    let mut next_rotation = |i| {
+        let cur = shape;
+        shape = shape.rotate_cw();
+        if i == 3 {
+            shape = shape.flipped();
+        }
+        Some(cur)
+    };
+
+    let mut rotations: [Option<Shape>; 4] = array::from_fn(&mut next_rotation);
+    let mut flipped_rots: [Option<Shape>; 4] = array::from_fn(&mut next_rotation);
+
+    for i in 0..rotations.len() {
+        if rotations[..i].contains(&rotations[i]) {
+            rotations[i] = None;
+        }
+    }
+    for i in 0..flipped_rots.len() {
+        if rotations.contains(&rotations[i]) || flipped_rots[..i].contains(&rotations[i]) {
+            flipped_rots[i] = None;
+        }
+    }
+
+    (rotations, flipped_rots)
+}
+
+pub struct Presents {
+    pub shapes: Vec<ShapeInfo>,
+    pub regions: Vec<Region>,
+}
+
+pub fn parse_presents<'a>(lines: impl IntoIterator<Item = &'a str>) -> Presents {
+    let mut shapes: Vec<ShapeInfo> = Vec::new();
+    let mut regions: Vec<Region> = Vec::new();
+
+    let mut lines = lines.into_iter();
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            break;
+        }
+
+        let mut it = line.split_ascii_whitespace();
+        let descriptor = it.next().unwrap().strip_suffix(':').unwrap();
+        let shape_counts = it.map(|s| s.parse::<u32>().unwrap()).collect::<Vec<_>>();
+
+        if shape_counts.is_empty() {
+            let shape_id = descriptor.parse::<usize>().unwrap();
+            assert_eq!(shape_id, shapes.len());
+
+            let cells = Shape(array::from_fn(|_| {
+                let line_s = lines.next().unwrap();
+                let mut line_chars = line_s.chars().map(|c| c == '#');
+                let shape_line = array::from_fn(|_| line_chars.next().unwrap());
+                assert_eq!(line_chars.next(), None);
+                shape_line
+            }));
+
+            let (rotations, flipped_rots) = create_rotations(cells);
+            let area = cells.0.iter().flatten().filter(|c| **c).count() as u32;
+
+            shapes.push(ShapeInfo { rotations, flipped_rots, area });
+
+            let empty_line = lines.next().unwrap();
+            assert!(empty_line.is_empty());
+        } else {
+            let (w, h) = descriptor.split_once('x').unwrap();
+            let dim = [w.parse().unwrap(), h.parse().unwrap()];
+
+            regions.push(Region { dim, shape_counts });
+        }
+    }
+
+    Presents { shapes, regions }
+}
+
+/// Number of regions the presents fit in. Checks `cancel` between regions, reporting the count so
+/// far as a lower bound if it trips.
+pub fn count_fitting_regions(presents: &Presents, cancel: &Cancel) -> Result<usize, Cancelled> {
+    let mut progress = Progress::new("region", presents.regions.len());
+    let mut fitting = 0;
+    for (i, region) in presents.regions.iter().enumerate() {
+        cancel.check().map_err(|c| c.at_record("region", i).with_best(format!("at least {fitting} regions")))?;
+        fitting += usize::from(region.fits_by_area(&presents.shapes));
+        progress.advance();
+    }
+    Ok(fitting)
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Presents;
+
+    fn parse(input: &str) -> Presents {
+        parse_presents(input.lines())
+    }
+
+    fn part1(presents: &Presents) -> PartAnswer {
+        Ok(count_fitting_regions(presents, &Cancel::current())?.to_string())
+    }
+}
@@ -0,0 +1,83 @@
+/// What happened during a single rotation of the dial.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RotationEvent {
+    /// Signed number of clicks. Negative values turn left.
+    pub movement: i64,
+    pub start: i64,
+    pub end: i64,
+    /// Number of clicks that left the dial pointing at a target, including the final one.
+    pub passes: u64,
+    /// Whether the dial ended the rotation pointing at a target.
+    pub lands: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Dial {
+    size: i64,
+    position: i64,
+    targets: Vec<i64>,
+}
+
+impl Dial {
+    pub fn new(size: i64, start: i64, targets: &[i64]) -> Dial {
+        assert!(size > 0);
+        let mut targets = targets.iter().map(|t| t.rem_euclid(size)).collect::<Vec<_>>();
+        targets.sort_unstable();
+        targets.dedup();
+        Dial {
+            size,
+            position: start.rem_euclid(size),
+            targets,
+        }
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// Turns the dial by `movement` clicks, counting target passes arithmetically.
+    pub fn rotate(&mut self, movement: i64) -> RotationEvent {
+        let start = self.position;
+        let mut passes = 0;
+        for &target in &self.targets {
+            // Relative to the target, passing it is the same as passing zero
+            let relative_pos = (start - target).rem_euclid(self.size);
+            // Turning left from zero only reaches it again after a full turn, so count from one
+            // click before it instead
+            let adjusted_dial = if movement < 0 {
+                (relative_pos - 1).rem_euclid(self.size)
+            } else {
+                relative_pos
+            };
+            passes += (adjusted_dial + movement).div_euclid(self.size).unsigned_abs();
+        }
+        self.position = (start + movement).rem_euclid(self.size);
+        RotationEvent {
+            movement,
+            start,
+            end: self.position,
+            passes,
+            lands: self.targets.binary_search(&self.position).is_ok(),
+        }
+    }
+
+    /// Turns the dial by `movement` clicks one click at a time. Reference implementation for
+    /// [`Dial::rotate`].
+    pub fn rotate_clicks(&mut self, movement: i64) -> RotationEvent {
+        let start = self.position;
+        let mut passes = 0;
+        for _ in 0..movement.unsigned_abs() {
+            self.position = (self.position + movement.signum()).rem_euclid(self.size);
+            if self.targets.contains(&self.position) {
+                passes += 1;
+            }
+        }
+        RotationEvent {
+            movement,
+            start,
+            end: self.position,
+            passes,
+            lands: self.targets.contains(&self.position),
+        }
+    }
+}
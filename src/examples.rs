@@ -0,0 +1,65 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle examples are kept. Unlike the real inputs they're checked in.
+pub const DIR: &str = "examples";
+
+/// Expected answers for the examples, one `<file> <part> <answer>` per line, with `file` relative
+/// to [`DIR`]. Its name starts with `dayNN`, since some days have more than one example.
+pub const MANIFEST: &str = "examples/answers.txt";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Example {
+    pub file: String,
+    pub part: u32,
+    pub answer: String,
+}
+
+impl Example {
+    /// `None` if the file name doesn't start with `dayNN`, which [`parse_manifest`] rules out.
+    pub fn day(&self) -> Option<u32> {
+        day_of(&self.file)
+    }
+
+    pub fn path(&self) -> PathBuf {
+        Path::new(DIR).join(&self.file)
+    }
+}
+
+/// The day number in a file name like `day11b.txt`.
+pub fn day_of(file: &str) -> Option<u32> {
+    let digits = file.strip_prefix("day")?;
+    let len = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+    digits[..len].parse().ok()
+}
+
+/// Name of a day's (first) example file.
+pub fn file_name(day: u32) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// Parses the manifest, see [`MANIFEST`]. A malformed line is an `InvalidData` error naming it.
+pub fn parse_manifest(text: &str) -> io::Result<Vec<Example>> {
+    text.lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let mut it = line.splitn(3, ' ');
+            match (it.next(), it.next().and_then(|s| s.parse().ok()), it.next()) {
+                (Some(file), Some(part), Some(answer)) if day_of(file).is_some() => {
+                    Ok(Example { file: file.to_string(), part, answer: answer.trim().to_string() })
+                }
+                _ => {
+                    let message = format!("{MANIFEST} line {}: expected <dayNN file> <part> <answer>, got {line:?}", i + 1);
+                    Err(io::Error::new(io::ErrorKind::InvalidData, message))
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn load_manifest() -> io::Result<Vec<Example>> {
+    parse_manifest(&fs::read_to_string(MANIFEST)?)
+}
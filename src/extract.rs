@@ -0,0 +1,111 @@
+//! Pulls the example input and answers out of a saved puzzle page.
+//!
+//! Each part of the puzzle is an `<article>`. Its first `<pre><code>` block is taken as the
+//! example, and the last `<code><em>` in it as the example's answer: a bare `<em>` is usually
+//! the question itself, which comes after the example. A part without a block of its own uses the
+//! one before it.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::examples::{self, Example};
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = rest[start..].split_once('>').map_or("", |(_, after)| after);
+    }
+    out.push_str(rest);
+    decode_entities(&out)
+}
+
+/// Text between each `open` and the following `close`.
+fn between<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(open).skip(1).filter_map(move |s| s.split_once(close).map(|(inside, _)| inside))
+}
+
+/// Example blocks and answer of one part.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+pub fn parse_page(html: &str) -> Vec<PartExample> {
+    between(html, "<article", "</article>")
+        .map(|article| PartExample {
+            input: between(article, "<pre><code>", "</code></pre>").next().map(strip_tags),
+            answer: between(article, "<code><em>", "</em></code>").last().map(strip_tags),
+        })
+        .collect()
+}
+
+/// Example files and manifest entries for `day`. Parts sharing an example share the file, and
+/// parts without an answer are left out.
+pub fn examples_for(day: u32, parts: &[PartExample]) -> (Vec<(String, String)>, Vec<Example>) {
+    let mut files: Vec<(String, String)> = Vec::new();
+    let mut entries = Vec::new();
+    for (part, example) in (1..).zip(parts) {
+        if let Some(input) = &example.input
+            && files.last().is_none_or(|(_, last)| last != input)
+        {
+            let name = match files.len() {
+                0 => examples::file_name(day),
+                n => format!("day{day:02}{}.txt", char::from(b'a' + n as u8)),
+            };
+            files.push((name, input.clone()));
+        }
+        if let (Some((file, _)), Some(answer)) = (files.last(), &example.answer) {
+            entries.push(Example { file: file.clone(), part, answer: answer.clone() });
+        }
+    }
+    (files, entries)
+}
+
+/// Drops the manifest lines for `day`, commented out or not, and appends `entries`.
+pub fn update_manifest(manifest: &str, day: u32, entries: &[Example]) -> String {
+    let mut out = String::new();
+    for line in manifest.lines() {
+        let file = line.trim_start_matches(['#', ' ']).split(' ').next().unwrap_or("");
+        if examples::day_of(file) != Some(day) {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    for e in entries {
+        out.push_str(&format!("{} {} {}\n", e.file, e.part, e.answer));
+    }
+    out
+}
+
+/// Writes the examples found in the page at `page` into [`examples::DIR`] and the manifest.
+/// Returns the new manifest entries.
+pub fn extract(day: u32, page: &Path) -> io::Result<Vec<Example>> {
+    let parts = parse_page(&fs::read_to_string(page)?);
+    let (files, entries) = examples_for(day, &parts);
+    if files.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, format!("No example in {}", page.display())));
+    }
+    fs::create_dir_all(examples::DIR)?;
+    for (name, input) in &files {
+        fs::write(Path::new(examples::DIR).join(name), input)?;
+    }
+    let manifest = fs::read_to_string(examples::MANIFEST).or_else(|e| match e.kind() {
+        io::ErrorKind::NotFound => Ok(String::new()),
+        _ => Err(e),
+    })?;
+    // Rewriting a manifest that doesn't parse would hide the lines that are wrong
+    examples::parse_manifest(&manifest)?;
+    fs::write(examples::MANIFEST, update_manifest(&manifest, day, &entries))?;
+    Ok(entries)
+}
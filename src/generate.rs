@@ -0,0 +1,451 @@
+//! Random puzzle inputs in the same format as the real ones, for tests and benchmarks.
+//!
+//! Every generator takes two size knobs. `records` is the number of top-level items (lines,
+//! ranges, problems, ...) and `width` the size of each one; what they mean exactly for each day
+//! is listed in [`describe_size`].
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::io;
+use std::ops::RangeInclusive;
+use crate::rng::Rng;
+
+pub const DAYS: RangeInclusive<u32> = 1..=12;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Size {
+    pub records: Option<usize>,
+    pub width: Option<usize>,
+}
+
+impl Size {
+    fn get(&self, default_records: usize, default_width: usize) -> (usize, usize) {
+        (self.records.unwrap_or(default_records), self.width.unwrap_or(default_width))
+    }
+}
+
+/// What the `records` and `width` knobs control for `day`.
+pub fn describe_size(day: u32) -> Option<(&'static str, &'static str)> {
+    Some(match day {
+        1 => ("rotations", "largest rotation"),
+        2 => ("ranges", "maximum digits of an ID"),
+        3 => ("banks", "batteries per bank"),
+        4 => ("rows", "columns"),
+        5 => ("fresh ranges", "available IDs"),
+        6 => ("problems", "operands per problem"),
+        7 => ("rows", "columns"),
+        8 => ("junction boxes", "largest coordinate"),
+        9 => ("polygon columns", "largest coordinate"),
+        10 => ("machines", "most lights per machine"),
+        11 => ("layers of devices", "devices per layer"),
+        12 => ("regions", "largest region side"),
+        _ => return None,
+    })
+}
+
+/// Generates an input for `day`.
+///
+/// Fails with [`io::ErrorKind::NotFound`] if there is no generator for the day, and with
+/// [`io::ErrorKind::InvalidInput`] if the size is out of the range it supports.
+pub fn generate(day: u32, seed: u64, size: &Size) -> io::Result<String> {
+    let rng = &mut Rng::new(seed);
+    match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        _ => Err(io::Error::new(io::ErrorKind::NotFound, format!("No generator for day {day}"))),
+    }
+}
+
+fn invalid_size(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Random number with exactly `digits` digits.
+fn with_digits(rng: &mut Rng, digits: u32) -> u64 {
+    rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+}
+
+fn digit_string(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| char::from(b'1' + rng.below(9) as u8)).collect()
+}
+
+fn day01(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (rotations, max_clicks) = size.get(4000, 999);
+    if max_clicks == 0 {
+        return Err(invalid_size("Rotations need at least 1 click".to_string()));
+    }
+    let mut out = String::new();
+    for _ in 0..rotations {
+        let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+        writeln!(out, "{direction}{}", rng.range(1..=max_clicks as u64)).unwrap();
+    }
+    Ok(out)
+}
+
+fn day02(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    const MAX_RANGE_LEN: u64 = 200_000;
+    let (num_ranges, max_digits) = size.get(40, 10);
+    if num_ranges == 0 {
+        return Err(invalid_size("Needs at least 1 range".to_string()));
+    }
+    if !(1..=19).contains(&max_digits) {
+        return Err(invalid_size(format!("IDs need 1 to 19 digits, not {max_digits}")));
+    }
+
+    let mut candidates = (0..num_ranges).map(|_| {
+        let digits = rng.range(1..=max_digits as u64) as u32;
+        let start = with_digits(rng, digits);
+        start..=start.saturating_add(rng.below(MAX_RANGE_LEN.min(start)))
+    }).collect::<Vec<_>>();
+    candidates.sort_by_key(|r| *r.start());
+
+    // The puzzle's ranges never overlap
+    let mut ranges: Vec<RangeInclusive<u64>> = Vec::new();
+    for range in candidates {
+        if ranges.last().is_none_or(|last| last.end() < range.start()) {
+            ranges.push(range);
+        }
+    }
+    rng.shuffle(&mut ranges);
+    let ranges = ranges.iter().map(|r| format!("{}-{}", r.start(), r.end())).collect::<Vec<_>>();
+    Ok(ranges.join(",") + "\n")
+}
+
+fn day03(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (banks, len) = size.get(200, 100);
+    // Part 2 turns on 12 batteries in every bank
+    if len < 12 {
+        return Err(invalid_size(format!("Banks need at least 12 batteries, not {len}")));
+    }
+    Ok((0..banks).map(|_| digit_string(rng, len) + "\n").collect())
+}
+
+fn day04(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (height, width) = size.get(135, 135);
+    let mut out = String::new();
+    for _ in 0..height {
+        out.extend((0..width).map(|_| if rng.chance(2, 3) { '@' } else { '.' }));
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn day05(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    const MAX_ID: u64 = 999_999_999_999_999;
+    const MAX_RANGE_LEN: u64 = 2_000_000_000_000;
+    let (num_ranges, num_ids) = size.get(180, 1000);
+
+    let ranges = (0..num_ranges).map(|_| {
+        let start = rng.range(1..=MAX_ID);
+        start..=start.saturating_add(rng.below(MAX_RANGE_LEN)).min(MAX_ID)
+    }).collect::<Vec<_>>();
+
+    let mut out = String::new();
+    for range in &ranges {
+        writeln!(out, "{}-{}", range.start(), range.end()).unwrap();
+    }
+    out.push('\n');
+    for _ in 0..num_ids {
+        // Half of the IDs are picked from the ranges so that they aren't all spoiled
+        let id = if !ranges.is_empty() && rng.chance(1, 2) {
+            let range = ranges[rng.index(ranges.len())].clone();
+            rng.range(range)
+        } else {
+            rng.range(1..=MAX_ID)
+        };
+        writeln!(out, "{id}").unwrap();
+    }
+    Ok(out)
+}
+
+fn day06(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (num_problems, num_operands) = size.get(1000, 4);
+    if num_problems == 0 || num_operands == 0 {
+        return Err(invalid_size("The worksheet needs at least 1 problem with 1 operand".to_string()));
+    }
+    let mut rows = vec![String::new(); num_operands + 1];
+    for i in 0..num_problems {
+        let operands = (0..num_operands).map(|_| {
+            let len = rng.range(1..=4) as usize;
+            digit_string(rng, len)
+        }).collect::<Vec<_>>();
+        let column_width = operands.iter().map(|s| s.len()).max().unwrap_or(1);
+        let right_aligned = rng.chance(1, 2);
+        let operator = if rng.chance(1, 2) { "+" } else { "*" };
+        for (row, operand) in rows.iter_mut().zip(operands.iter().map(String::as_str).chain([operator])) {
+            if i != 0 {
+                row.push(' ');
+            }
+            if right_aligned && operand != operator {
+                write!(row, "{operand:>column_width$}").unwrap();
+            } else {
+                write!(row, "{operand:<column_width$}").unwrap();
+            }
+        }
+    }
+    Ok(rows.iter().map(|row| format!("{row}\n")).collect())
+}
+
+fn day07(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (height, width) = size.get(142, 141);
+    if height == 0 || width == 0 {
+        return Err(invalid_size("The manifold needs at least 1 row and 1 column".to_string()));
+    }
+    let start_x = width / 2;
+    let mut out = String::new();
+    for y in 0..height {
+        let mut row = vec!['.'; width];
+        if y == 0 {
+            row[start_x] = 'S';
+        } else if y % 2 == 0 {
+            // Splitters fill a triangle below the start, as far as the beams can spread
+            let spread = y / 2 - 1;
+            for x in (start_x.saturating_sub(spread)..=start_x + spread).filter(|x| (x + spread + start_x).is_multiple_of(2)) {
+                if x < width && rng.chance(3, 4) {
+                    row[x] = '^';
+                }
+            }
+        }
+        out.extend(row);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn day08(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (num_boxes, max_coordinate) = size.get(1000, 100_000);
+    // Part 2 needs a connection between two of them
+    if num_boxes < 2 {
+        return Err(invalid_size(format!("Needs at least 2 junction boxes, not {num_boxes}")));
+    }
+    let mut out = String::new();
+    for _ in 0..num_boxes {
+        let [x, y, z] = [(); 3].map(|_| rng.range(0..=max_coordinate as u64));
+        writeln!(out, "{x},{y},{z}").unwrap();
+    }
+    Ok(out)
+}
+
+/// `count` distinct sorted even numbers in `2..=max`, so there is always a free tile between
+/// them.
+fn spaced_coordinates(rng: &mut Rng, count: usize, max: u64) -> io::Result<Vec<u64>> {
+    if count as u64 > max / 2 {
+        return Err(invalid_size(format!("Not enough room for {count} coordinates up to {max}")));
+    }
+    let mut coordinates = BTreeSet::new();
+    while coordinates.len() < count {
+        coordinates.insert(rng.range(1..=max / 2) * 2);
+    }
+    Ok(coordinates.into_iter().collect())
+}
+
+/// Removes repeated corners and corners in the middle of a straight edge.
+fn simplify_loop(mut points: Vec<[u64; 2]>) -> Vec<[u64; 2]> {
+    let mut i = 0;
+    while i < points.len() && points.len() > 2 {
+        let n = points.len();
+        let [prev, p, next] = [points[(i + n - 1) % n], points[i], points[(i + 1) % n]];
+        let repeated = p == prev;
+        let straight = (prev[0] == p[0] && p[0] == next[0]) || (prev[1] == p[1] && p[1] == next[1]);
+        if repeated || straight {
+            points.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    points
+}
+
+/// An x-monotone loop: the area between a random top and bottom outline, listed clockwise with
+/// y pointing down.
+fn day09(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (num_columns, max_coordinate) = size.get(120, 100_000);
+    if num_columns == 0 {
+        return Err(invalid_size("The loop needs at least 1 column".to_string()));
+    }
+    let xs = spaced_coordinates(rng, num_columns + 1, max_coordinate as u64)?;
+    let ys = spaced_coordinates(rng, num_columns + 1, max_coordinate as u64)?;
+
+    // Indices into `ys`. Neighbouring columns always overlap so the loop stays connected.
+    let mut tops = vec![rng.index(ys.len() - 1)];
+    let mut bottoms = vec![rng.range(tops[0] as u64 + 1..=ys.len() as u64 - 1) as usize];
+    for i in 1..num_columns {
+        let top = rng.index(bottoms[i - 1]);
+        let bottom = rng.range(top.max(tops[i - 1]) as u64 + 1..=ys.len() as u64 - 1) as usize;
+        tops.push(top);
+        bottoms.push(bottom);
+    }
+
+    let mut points = Vec::new();
+    for i in 0..num_columns {
+        points.push([xs[i], ys[tops[i]]]);
+        points.push([xs[i + 1], ys[tops[i]]]);
+    }
+    for i in (0..num_columns).rev() {
+        points.push([xs[i + 1], ys[bottoms[i]]]);
+        points.push([xs[i], ys[bottoms[i]]]);
+    }
+
+    Ok(simplify_loop(points).iter().map(|[x, y]| format!("{x},{y}\n")).collect())
+}
+
+fn day10(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    let (num_machines, max_lights) = size.get(150, 10);
+    // Part 1 tries every combination of buttons, so keep their number down
+    if !(2..=14).contains(&max_lights) {
+        return Err(invalid_size(format!("Machines need 2 to 14 lights, not {max_lights}")));
+    }
+    let mut out = String::new();
+    for _ in 0..num_machines {
+        let num_lights = rng.range(2..=max_lights as u64) as usize;
+        let num_buttons = rng.range(num_lights as u64 - 1..=(num_lights as u64 + 3).min(13)) as usize;
+        let mut buttons = (0..num_buttons).map(|_| {
+            let mut lights = BTreeSet::from([rng.index(num_lights)]);
+            lights.extend((0..num_lights).filter(|_| rng.chance(1, 3)));
+            lights
+        }).collect::<Vec<_>>();
+        for light in 0..num_lights {
+            if !buttons.iter().any(|b| b.contains(&light)) {
+                let button = rng.index(num_buttons);
+                buttons[button].insert(light);
+            }
+        }
+
+        // Work backwards from a known solution so that every machine is solvable
+        let mut lights = vec![false; num_lights];
+        let mut joltages = vec![0; num_lights];
+        for button in &buttons {
+            let presses = rng.range(0..=30);
+            let toggled = rng.chance(1, 2);
+            for &light in button {
+                joltages[light] += presses;
+                lights[light] ^= toggled;
+            }
+        }
+
+        out.push('[');
+        out.extend(lights.iter().map(|&on| if on { '#' } else { '.' }));
+        out.push(']');
+        for button in &buttons {
+            let lights = button.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+            write!(out, " ({})", lights.join(",")).unwrap();
+        }
+        let joltages = joltages.iter().map(|j| j.to_string()).collect::<Vec<_>>();
+        writeln!(out, " {{{}}}", joltages.join(",")).unwrap();
+    }
+    Ok(out)
+}
+
+/// A layered DAG from `svr` to `out`, passing `fft` and `dac` on the way, with `you` close to
+/// the end so the number of paths from it stays small.
+fn day11(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    const RESERVED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+    let (num_layers, layer_width) = size.get(24, 25);
+    if num_layers < 4 || layer_width < 3 {
+        return Err(invalid_size(format!("Needs at least 4 layers of 3 devices, not {num_layers} of {layer_width}")));
+    }
+
+    let mut used = BTreeSet::from(RESERVED.map(String::from));
+    let mut new_name = || loop {
+        let name = (0..3).map(|_| char::from(b'a' + rng.below(26) as u8)).collect::<String>();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+    let mut layers = vec![vec!["svr".to_string()]];
+    layers.extend((1..num_layers).map(|_| (0..layer_width).map(|_| new_name()).collect::<Vec<_>>()));
+    for (name, layer) in [("fft", num_layers / 3), ("dac", num_layers * 2 / 3), ("you", num_layers - 3)] {
+        let i = loop {
+            let i = rng.index(layer_width);
+            if !RESERVED.contains(&layers[layer][i].as_str()) {
+                break i;
+            }
+        };
+        layers[layer][i] = name.to_string();
+    }
+    layers.push(vec!["out".to_string()]);
+
+    let mut lines = Vec::new();
+    for pair in layers.windows(2) {
+        let [layer, next_layer] = pair else { unreachable!() };
+        let mut outs = layer.iter().map(|_| {
+            let degree = rng.range(1..=3.min(next_layer.len() as u64)) as usize;
+            let mut out = BTreeSet::new();
+            while out.len() < degree {
+                out.insert(rng.index(next_layer.len()));
+            }
+            out
+        }).collect::<Vec<_>>();
+        // Make every device reachable from `svr`
+        for target in 0..next_layer.len() {
+            if !outs.iter().any(|o| o.contains(&target)) {
+                let source = rng.index(outs.len());
+                outs[source].insert(target);
+            }
+        }
+        for (name, out) in layer.iter().zip(outs) {
+            let out = out.iter().map(|&i| next_layer[i].as_str()).collect::<Vec<_>>();
+            lines.push(format!("{name}: {}\n", out.join(" ")));
+        }
+    }
+    rng.shuffle(&mut lines);
+    Ok(lines.concat())
+}
+
+fn day12(rng: &mut Rng, size: &Size) -> io::Result<String> {
+    const NUM_SHAPES: usize = 6;
+    let (num_regions, max_side) = size.get(1000, 50);
+    if max_side < 3 {
+        return Err(invalid_size(format!("Regions need sides of at least 3, not {max_side}")));
+    }
+
+    let mut out = String::new();
+    let mut areas = Vec::new();
+    for i in 0..NUM_SHAPES {
+        let mut cells = [[false; 3]; 3];
+        let area = rng.range(5..=7);
+        let mut filled = 0;
+        while filled < area {
+            let cell = &mut cells[rng.index(3)][rng.index(3)];
+            if !*cell {
+                *cell = true;
+                filled += 1;
+            }
+        }
+        areas.push(area);
+        writeln!(out, "{i}:").unwrap();
+        for row in cells {
+            out.extend(row.map(|c| if c { '#' } else { '.' }));
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    for _ in 0..num_regions {
+        let sides = (max_side / 2).max(3) as u64..=max_side as u64;
+        let [width, height] = [(); 2].map(|_| rng.range(sides.clone()));
+        // Aim for anywhere between comfortably fitting and clearly too many presents
+        let target_area = width * height * rng.range(60..=110) / 100;
+        let mut counts = [0; NUM_SHAPES];
+        let mut total_area = 0;
+        while total_area < target_area {
+            let shape = rng.index(NUM_SHAPES);
+            counts[shape] += 1;
+            total_area += areas[shape];
+        }
+        let counts = counts.map(|c| c.to_string());
+        writeln!(out, "{width}x{height}: {}", counts.join(" ")).unwrap();
+    }
+    Ok(out)
+}
@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// Unsigned integers that solvers can be written generically over.
+///
+/// Arithmetic is checked so that fixed-width types report overflow instead of wrapping, letting
+/// callers retry with [`BigUint`], which never overflows.
+pub trait Integer: Clone + Ord + Debug + Display + FromStr {
+    fn from_u32(value: u32) -> Self;
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    /// Quotient and remainder. Panics if `rhs` is zero.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self);
+
+    fn zero() -> Self {
+        Self::from_u32(0)
+    }
+
+    fn one() -> Self {
+        Self::from_u32(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn checked_pow10(exp: u32) -> Option<Self> {
+        let ten = Self::from_u32(10);
+        (0..exp).try_fold(Self::one(), |x, _| x.checked_mul(&ten))
+    }
+
+    /// Number of decimal digits. Zero has one digit.
+    fn num_digits(&self) -> u32 {
+        let ten = Self::from_u32(10);
+        let mut x = self.clone();
+        let mut digits = 1;
+        while x >= ten {
+            x = x.div_rem(&ten).0;
+            digits += 1;
+        }
+        digits
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            fn from_u32(value: u32) -> Self {
+                value.into()
+            }
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *rhs)
+            }
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *rhs)
+            }
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *rhs)
+            }
+            fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+                (self / rhs, self % rhs)
+            }
+            fn num_digits(&self) -> u32 {
+                self.checked_ilog10().unwrap_or(0) + 1
+            }
+        }
+    )*};
+}
+
+impl_integer!(u32, u64, u128);
+
+/// Arbitrary-precision unsigned integer.
+#[derive(Clone, Default, Eq, PartialEq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without trailing zeros.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    fn normalize(mut self) -> BigUint {
+        while self.limbs.pop_if(|l| *l == 0).is_some() {}
+        self
+    }
+
+    fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 32 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    fn bit(&self, i: u64) -> bool {
+        self.limbs.get((i / 32) as usize).is_some_and(|l| l >> (i % 32) & 1 != 0)
+    }
+
+    fn mul_add_small(&mut self, mul: u32, add: u32) {
+        let mut carry = u64::from(add);
+        for l in &mut self.limbs {
+            let x = u64::from(*l) * u64::from(mul) + carry;
+            *l = x as u32;
+            carry = x >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    /// Divides in place, returning the remainder.
+    fn div_rem_small(&mut self, div: u32) -> u32 {
+        assert_ne!(div, 0);
+        let mut rem = 0u64;
+        for l in self.limbs.iter_mut().rev() {
+            let x = rem << 32 | u64::from(*l);
+            *l = (x / u64::from(div)) as u32;
+            rem = x % u64::from(div);
+        }
+        *self = std::mem::take(self).normalize();
+        rem as u32
+    }
+
+    fn shl1_or(&mut self, low_bit: bool) {
+        let mut carry = u32::from(low_bit);
+        for l in &mut self.limbs {
+            let next_carry = *l >> 31;
+            *l = *l << 1 | carry;
+            carry = next_carry;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint { limbs: vec![value as u32, (value >> 32) as u32] }.normalize()
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Integer for BigUint {
+    fn from_u32(value: u32) -> Self {
+        BigUint::from(u64::from(value))
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(rhs.limbs.len()) + 1);
+        let mut carry = 0u64;
+        for i in 0..self.limbs.len().max(rhs.limbs.len()) {
+            let x = u64::from(*self.limbs.get(i).unwrap_or(&0)) + u64::from(*rhs.limbs.get(i).unwrap_or(&0)) + carry;
+            limbs.push(x as u32);
+            carry = x >> 32;
+        }
+        limbs.push(carry as u32);
+        Some(BigUint { limbs }.normalize())
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if self < rhs {
+            return None;
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, l) in self.limbs.iter().enumerate() {
+            let mut x = i64::from(*l) - i64::from(*rhs.limbs.get(i).unwrap_or(&0)) - borrow;
+            borrow = 0;
+            if x < 0 {
+                x += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(x as u32);
+        }
+        Some(BigUint { limbs }.normalize())
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, b) in rhs.limbs.iter().enumerate() {
+                let x = u64::from(limbs[i + j]) + u64::from(*a) * u64::from(*b) + carry;
+                limbs[i + j] = x as u32;
+                carry = x >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        Some(BigUint { limbs }.normalize())
+    }
+
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "Division by zero");
+        if let [div] = rhs.limbs[..] {
+            let mut quotient = self.clone();
+            let rem = quotient.div_rem_small(div);
+            return (quotient, BigUint::from_u32(rem));
+        }
+
+        // Binary long division
+        let mut quotient = BigUint { limbs: vec![0; self.limbs.len()] };
+        let mut rem = BigUint::zero();
+        for i in (0..self.bits()).rev() {
+            rem.shl1_or(self.bit(i));
+            if rem >= *rhs {
+                rem = rem.checked_sub(rhs).unwrap();
+                quotient.limbs[(i / 32) as usize] |= 1 << (i % 32);
+            }
+        }
+        (quotient.normalize(), rem)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseBigUintError;
+
+impl Display for ParseBigUintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseBigUintError);
+        }
+        let mut result = BigUint::zero();
+        for c in s.chars() {
+            let d = c.to_digit(10).ok_or(ParseBigUintError)?;
+            result.mul_add_small(10, d);
+        }
+        Ok(result.normalize())
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+        let mut x = self.clone();
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(x.div_rem_small(CHUNK));
+            if x.is_zero() {
+                break;
+            }
+        }
+        let mut s = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            s += &format!("{chunk:09}");
+        }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl Debug for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
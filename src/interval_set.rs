@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Integer types usable as interval bounds.
+pub trait Bound: Copy + Ord {
+    fn checked_succ(self) -> Option<Self>;
+    fn checked_pred(self) -> Option<Self>;
+    /// Number of values in `start..=end`. Returned as `u128` so the full range of `u64` fits.
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {$(
+        impl Bound for $t {
+            fn checked_succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+            fn checked_pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+            fn span(start: Self, end: Self) -> u128 {
+                (end as i128 - start as i128) as u128 + 1
+            }
+        }
+    )*};
+}
+
+impl_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers stored as disjoint, non-adjacent inclusive intervals.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet<T> {
+    /// Maps each interval's start to its (inclusive) end
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: BTreeMap::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of disjoint intervals in the set.
+    pub fn num_intervals(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Total number of values in the set.
+    pub fn covered_len(&self) -> u128 {
+        self.intervals.iter().map(|(&s, &e)| T::span(s, e)).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|(&s, &e)| s..=e)
+    }
+
+    /// Adds all values in `range`, merging it with any intervals it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&s, &e)) = self.intervals.range(..=start).next_back() {
+            // Merge with preceding interval if it reaches up to `start`
+            if e.checked_succ().is_none_or(|after_e| after_e >= start) {
+                self.intervals.remove(&s);
+                start = s;
+                end = end.max(e);
+            }
+        }
+        loop {
+            // Merge with following intervals starting at or right after `end`
+            let next = match end.checked_succ() {
+                Some(after_end) => self.intervals.range(start..=after_end).next(),
+                None => self.intervals.range(start..).next(),
+            };
+            let Some((&s, &e)) = next else { break };
+            self.intervals.remove(&s);
+            end = end.max(e);
+        }
+        self.intervals.insert(start, end);
+    }
+
+    /// Removes all values in `range`, splitting intervals that extend past either side of it.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        let overlapping = self.overlapping(start..=end).collect::<Vec<_>>();
+        for interval in overlapping {
+            let (s, e) = interval.into_inner();
+            self.intervals.remove(&s);
+            if s < start {
+                self.intervals.insert(s, start.checked_pred().unwrap());
+            }
+            if e > end {
+                self.intervals.insert(end.checked_succ().unwrap(), e);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.containing(value).is_some()
+    }
+
+    /// The interval containing `value`, if any.
+    pub fn containing(&self, value: T) -> Option<RangeInclusive<T>> {
+        self.intervals.range(..=value).next_back()
+            .filter(|(_, e)| **e >= value)
+            .map(|(&s, &e)| s..=e)
+    }
+
+    /// Whether every value in `range` is in the set.
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        start > end || self.containing(start).is_some_and(|r| *r.end() >= end)
+    }
+
+    /// All intervals sharing at least one value with `range`, unclipped and in order.
+    pub fn overlapping(&self, range: RangeInclusive<T>) -> impl Iterator<Item=RangeInclusive<T>> + '_ {
+        let (start, end) = range.into_inner();
+        let valid = start <= end;
+        let first = self.intervals.range(..start).next_back()
+            .filter(|(_, e)| valid && **e >= start);
+        let rest = valid.then(|| self.intervals.range(start..=end));
+        first.into_iter()
+            .chain(rest.into_iter().flatten())
+            .map(|(&s, &e)| s..=e)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        for range in self.iter() {
+            for overlap in other.overlapping(range.clone()) {
+                let start = *range.start().max(overlap.start());
+                let end = *range.end().min(overlap.end());
+                result.intervals.insert(start, end);
+            }
+        }
+        result
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: Bound> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item=RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Bound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
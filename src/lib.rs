@@ -0,0 +1,29 @@
+pub mod alloc;
+pub mod animation;
+pub mod answers;
+pub mod automaton;
+pub mod cancel;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod dial;
+pub mod examples;
+pub mod extract;
+pub mod generate;
+pub mod integer;
+pub mod interval_set;
+pub mod log;
+pub mod progress;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
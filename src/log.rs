@@ -0,0 +1,189 @@
+//! Diagnostics for the solvers, written to stderr so they never mix with the answers on stdout.
+//!
+//! Messages have a level and a target, the module they come from with the crate name dropped
+//! (`day11`, `day10p2_manual`, ...). Which ones are shown is set by `AOC_LOG`, a comma-separated
+//! list of `level` or `target=level` directives, e.g. `AOC_LOG=info,day10=trace`. A target
+//! directive applies to every target starting with it. Only warnings and errors are shown by
+//! default.
+//!
+//! Spans indent the messages logged while they're alive, to follow recursive searches.
+
+use std::cell::Cell;
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Once, RwLock};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+    pub fn parse(s: &str) -> Option<Level> {
+        Level::ALL.into_iter().find(|level| level.name().eq_ignore_ascii_case(s))
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+struct Filter {
+    default: Level,
+    /// Most specific last, so the last matching directive wins.
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    fn level(&self, target: &str) -> Level {
+        self.targets.iter().rev()
+            .find(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> Level {
+        self.targets.iter().map(|(_, level)| *level).fold(self.default, Level::max)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter { default: Level::Warn, targets: Vec::new() });
+/// Most verbose level any target is shown at, to skip the lock for disabled messages.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static FROM_ENV: Once = Once::new();
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Adds the directives in `spec`, in the same format as `AOC_LOG`. Returns `None` if any of them
+/// is invalid, in which case none are applied.
+pub fn add_directives(spec: &str) -> Option<()> {
+    let mut default = None;
+    let mut targets = Vec::new();
+    for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        match directive.split_once('=') {
+            Some((target, level)) => targets.push((target.to_string(), Level::parse(level)?)),
+            None => default = Some(Level::parse(directive)?),
+        }
+    }
+
+    let mut filter = FILTER.write().unwrap();
+    filter.default = default.unwrap_or(filter.default);
+    filter.targets.extend(targets);
+    filter.targets.sort_by_key(|(prefix, _)| prefix.len());
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    Some(())
+}
+
+/// Shows messages from targets starting with `target` up to `level`.
+pub fn set_level(target: &str, level: Level) {
+    add_directives(&format!("{target}={level}")).unwrap();
+}
+
+fn init_from_env() {
+    FROM_ENV.call_once(|| {
+        if let Ok(spec) = env::var("AOC_LOG")
+            && add_directives(&spec).is_none()
+        {
+            eprintln!("Ignoring invalid AOC_LOG: {spec:?}");
+        }
+    });
+}
+
+fn short_target(module_path: &str) -> &str {
+    module_path.strip_prefix("aoc_2025::").unwrap_or(module_path)
+}
+
+pub fn enabled(module_path: &str, level: Level) -> bool {
+    init_from_env();
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed) && level <= FILTER.read().unwrap().level(short_target(module_path))
+}
+
+/// Writes a message unconditionally. Use the macros, which check [`enabled`] first.
+pub fn write(module_path: &str, level: Level, args: fmt::Arguments) {
+    let indent = DEPTH.with(Cell::get);
+    let mut stderr = io::stderr().lock();
+    let _ = writeln!(stderr, "{:5} {}: {:indent$}{args}", level, short_target(module_path), "", indent = indent * 2);
+}
+
+/// Indents the messages logged on this thread until dropped, if its own message was shown.
+#[must_use]
+pub struct Span {
+    entered: bool,
+}
+
+impl Span {
+    pub fn enter(module_path: &str, level: Level, args: fmt::Arguments) -> Span {
+        let entered = enabled(module_path, level);
+        if entered {
+            write(module_path, level, args);
+            DEPTH.with(|depth| depth.set(depth.get() + 1));
+        }
+        Span { entered }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if self.entered {
+            DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $level) {
+            $crate::log::write(module_path!(), $level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+/// Logs a message at `level` and indents everything logged until the returned [`Span`] is dropped.
+#[macro_export]
+macro_rules! span {
+    ($level:expr, $($arg:tt)+) => {
+        $crate::log::Span::enter(module_path!(), $level, format_args!($($arg)+))
+    };
+}
@@ -0,0 +1,137 @@
+//! Progress of solvers that work through their input one record at a time.
+//!
+//! Solvers create a [`Progress`] and advance it after each record. It does nothing unless a sink
+//! was installed for the current thread with [`install`], which the runner does when stderr is a
+//! terminal, see [`stderr_sink`].
+
+use std::cell::{Cell, RefCell};
+use std::io::{self, Write};
+use std::panic;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// How far a solver is through its records.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Update {
+    /// Kind of record, e.g. `"machine"`.
+    pub kind: &'static str,
+    pub done: usize,
+    pub total: usize,
+    pub elapsed: Duration,
+    /// Set on the last update, sent when the [`Progress`] is dropped, even if it stopped early.
+    pub finished: bool,
+}
+
+impl Update {
+    /// Time left if the remaining records take as long as the ones done so far.
+    pub fn eta(&self) -> Option<Duration> {
+        let done = u32::try_from(self.done).ok().filter(|&done| done > 0)?;
+        Some(self.elapsed / done * u32::try_from(self.total.saturating_sub(self.done)).ok()?)
+    }
+}
+
+type Sink = Rc<dyn Fn(&Update)>;
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Sends the updates of every [`Progress`] created on this thread to `sink` while `f` runs.
+pub fn install<T>(sink: impl Fn(&Update) + 'static, f: impl FnOnce() -> T) -> T {
+    let previous = SINK.with(|current| current.replace(Some(Rc::new(sink))));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    SINK.with(|current| current.replace(previous));
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Counts the records a solver is done with, reporting to the installed sink if there is one.
+pub struct Progress {
+    kind: &'static str,
+    done: usize,
+    total: usize,
+    start: Instant,
+    sink: Option<Sink>,
+}
+
+impl Progress {
+    pub fn new(kind: &'static str, total: usize) -> Progress {
+        let sink = SINK.with(|current| current.borrow().clone());
+        Progress { kind, done: 0, total, start: Instant::now(), sink }
+    }
+
+    /// Marks one more record as done.
+    pub fn advance(&mut self) {
+        self.done += 1;
+        self.send(false);
+    }
+
+    fn send(&self, finished: bool) {
+        if let Some(sink) = &self.sink {
+            sink(&Update { kind: self.kind, done: self.done, total: self.total, elapsed: self.start.elapsed(), finished });
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.send(true);
+    }
+}
+
+/// How [`stderr_sink`] shows progress.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Style {
+    /// A bar redrawn in place, for a single solver running at a time.
+    Bar,
+    /// A line every few seconds, which can be interleaved with other solvers' lines.
+    Lines,
+}
+
+const BAR_WIDTH: usize = 30;
+
+/// `label` and the state of `update`, with a bar if `bar` is set.
+pub fn describe(label: &str, update: &Update, bar: bool) -> String {
+    let mut line = format!("{label}: {} {}/{}", update.kind, update.done, update.total);
+    if bar {
+        let filled = (update.done * BAR_WIDTH).checked_div(update.total).unwrap_or(BAR_WIDTH).min(BAR_WIDTH);
+        line = format!("{line} [{}{}]", "#".repeat(filled), " ".repeat(BAR_WIDTH - filled));
+    }
+    if let Some(eta) = update.eta() {
+        line = format!("{line} ETA {:.1}s", eta.as_secs_f64());
+    }
+    line
+}
+
+/// Shows updates on stderr in `style`, labelled with `label`. Only meant for a terminal, since the
+/// bar is drawn with carriage returns.
+pub fn stderr_sink(label: String, style: Style) -> impl Fn(&Update) {
+    let (interval, bar) = match style {
+        Style::Bar => (Duration::from_millis(100), true),
+        Style::Lines => (Duration::from_secs(2), false),
+    };
+    let last_shown = Cell::new(Duration::ZERO);
+    let drawn = Cell::new(false);
+    move |update| {
+        if update.finished {
+            if drawn.replace(false) {
+                // Clear the bar, leaving the line for whatever is printed next
+                let _ = write!(io::stderr(), "\r\x1b[K");
+            }
+            // The next update is from a new Progress, timed from zero again
+            last_shown.set(Duration::ZERO);
+            return;
+        }
+        if update.elapsed < last_shown.get() + interval {
+            return;
+        }
+        last_shown.set(update.elapsed);
+        let mut stderr = io::stderr().lock();
+        let _ = match style {
+            Style::Bar => {
+                drawn.set(true);
+                write!(stderr, "\r\x1b[K{}", describe(&label, update, bar))
+            }
+            Style::Lines => writeln!(stderr, "{}", describe(&label, update, bar)),
+        };
+    }
+}
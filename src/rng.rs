@@ -0,0 +1,42 @@
+use std::ops::RangeInclusive;
+
+/// Small seeded xorshift64* generator, so generated inputs are reproducible everywhere.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Uniform in `0..n`, or any value if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        if n == 0 { self.next_u64() } else { self.next_u64() % n }
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        range.start() + self.below((range.end() - range.start()).wrapping_add(1))
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::alloc::{self, AllocStats};
+use crate::cancel::Cancel;
+use crate::progress::{self, Style};
+use crate::solver::{PartAnswer, Solver};
+use crate::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12};
+
+/// A part's answer, or the message it panicked with or why it was cancelled.
+pub type Answer = Result<String, String>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Answer,
+    /// `None` for days without a second part.
+    pub part2: Option<Answer>,
+}
+
+/// How long each phase of a run took, measured with a monotonic clock.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Timings {
+    /// Reading the input file.
+    pub load: Duration,
+    pub parse: Duration,
+    pub part1: Duration,
+    /// `None` for days without a second part.
+    pub part2: Option<Duration>,
+    pub memory: Memory,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.load + self.parse + self.part1 + self.part2.unwrap_or_default()
+    }
+}
+
+/// Heap use of each phase. All zero unless the binary counts allocations, see [`alloc`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Memory {
+    pub load: AllocStats,
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: Option<AllocStats>,
+    /// The whole run. Its peak is the high-water mark over all phases, including what earlier
+    /// phases still hold, such as the input and its parsed form while the parts run.
+    pub overall: AllocStats,
+}
+
+fn timed<T>(duration: &mut Duration, memory: &mut AllocStats, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let (result, stats) = alloc::measure(f);
+    *duration = start.elapsed();
+    *memory = stats;
+    result
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Runs `f`, turning a panic into an error so that it doesn't take down the other parts or days.
+fn isolated<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+/// A part's answer, or the message it panicked with or why it was cancelled.
+fn answer(part: Result<PartAnswer, String>) -> Answer {
+    part.and_then(|answer| answer.map_err(|e| e.to_string()))
+}
+
+/// Per-record detail behind each part's answer, such as one value per input line.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Records {
+    /// `None` for days that don't break the answer down, or if the part failed.
+    pub part1: Option<Vec<String>>,
+    pub part2: Option<Vec<String>>,
+}
+
+/// 64-bit FNV-1a hash of the input, to tell which input a result belongs to without sharing it.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// How to run a day, beyond solving it.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Collect [`Records`] after solving.
+    pub records: bool,
+    /// Time budget of each part, after which the searches it runs are cancelled.
+    pub timeout: Option<Duration>,
+    /// Show the progress of solvers that go through their records one by one on stderr.
+    pub progress: Option<Style>,
+}
+
+/// Runs `f` with a [`Cancel`] token that trips after `timeout`, if there is one.
+fn budgeted<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> T {
+    match timeout {
+        Some(timeout) => Cancel::with_timeout(timeout).install(f),
+        None => f(),
+    }
+}
+
+/// Solves both parts. If parsing fails, both parts fail, since there is no telling whether the
+/// day has a second part.
+fn solve_timed<S: Solver>(input: &str, timings: &mut Timings, options: RunOptions, records: Option<&mut Records>) -> Answers {
+    let mut part2_time = Duration::ZERO;
+    let mut part2_memory = AllocStats::default();
+    let answers = match timed(&mut timings.parse, &mut timings.memory.parse, || isolated(|| S::parse(input))) {
+        Ok(parsed) => {
+            let part1 = timed(&mut timings.part1, &mut timings.memory.part1, || {
+                isolated(|| budgeted(options.timeout, || S::part1(&parsed)))
+            });
+            let part2 = timed(&mut part2_time, &mut part2_memory, || {
+                isolated(|| budgeted(options.timeout, || S::part2(&parsed)))
+            });
+            let answers = Answers { part1: answer(part1), part2: part2.transpose().map(answer) };
+            // Collected after the timed parts, since it may repeat their work.
+            if let Some(records) = records {
+                if answers.part1.is_ok() {
+                    records.part1 = isolated(|| budgeted(options.timeout, || S::part1_records(&parsed))).ok().flatten();
+                }
+                if answers.part2.as_ref().is_some_and(Result::is_ok) {
+                    records.part2 = isolated(|| budgeted(options.timeout, || S::part2_records(&parsed))).ok().flatten();
+                }
+            }
+            answers
+        }
+        Err(message) => Answers {
+            part1: Err(format!("parse: {message}")),
+            part2: Some(Err(format!("parse: {message}"))),
+        },
+    };
+    timings.part2 = answers.part2.is_some().then_some(part2_time);
+    timings.memory.part2 = answers.part2.is_some().then_some(part2_memory);
+    answers
+}
+
+/// Where the puzzle inputs are kept. Ignored by git, since inputs can't be shared.
+pub const INPUT_DIR: &str = "local/inputs";
+
+/// The outcome of running a day on an input file.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub answers: Answers,
+    pub timings: Timings,
+    pub records: Records,
+    pub input_hash: u64,
+}
+
+pub struct Day {
+    pub day: u32,
+    solve: fn(&str, &mut Timings, RunOptions, Option<&mut Records>) -> Answers,
+}
+
+impl Day {
+    const fn new<S: Solver>(day: u32) -> Day {
+        Day { day, solve: solve_timed::<S> }
+    }
+
+    /// Where the day's puzzle input is kept, in [`INPUT_DIR`].
+    pub fn default_input_path(&self) -> PathBuf {
+        Path::new(INPUT_DIR).join(format!("day{:02}.txt", self.day))
+    }
+
+    /// Solves both parts of an already loaded input. The load time is left at zero.
+    pub fn solve(&self, input: &str) -> (Answers, Timings) {
+        let mut timings = Timings::default();
+        let (answers, overall) = alloc::measure(|| (self.solve)(input, &mut timings, RunOptions::default(), None));
+        timings.memory.overall = overall;
+        (answers, timings)
+    }
+
+    /// Loads the input from `path` and solves both parts as set by `options`.
+    pub fn run(&self, path: &Path, options: RunOptions) -> io::Result<Run> {
+        let mut timings = Timings::default();
+        let mut records = Records::default();
+        let (run, overall) = alloc::measure(|| {
+            let input = timed(&mut timings.load, &mut timings.memory.load, || fs::read_to_string(path))?;
+            let records_out = options.records.then_some(&mut records);
+            let answers = match options.progress {
+                Some(style) => {
+                    let sink = progress::stderr_sink(format!("day {}", self.day), style);
+                    progress::install(sink, || (self.solve)(&input, &mut timings, options, records_out))
+                }
+                None => (self.solve)(&input, &mut timings, options, records_out),
+            };
+            io::Result::Ok((answers, input_hash(&input)))
+        });
+        let (answers, input_hash) = run?;
+        timings.memory.overall = overall;
+        Ok(Run { answers, timings, records, input_hash })
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+];
+
+pub fn find_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Runs every day in [`DAYS`] on its default input, spread over the available cores. Results are
+/// in the same order as `DAYS`.
+pub fn run_all(options: RunOptions) -> Vec<io::Result<Run>> {
+    let workers = thread::available_parallelism().map_or(1, |n| n.get()).min(DAYS.len());
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..DAYS.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = DAYS.get(i) else { break };
+                let result = day.run(&day.default_input_path(), options);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+    results.into_inner().unwrap().into_iter().map(Option::unwrap).collect()
+}
+
+/// Smallest, median and largest of a set of repeated measurements.
+#[derive(Copy, Clone, Debug)]
+pub struct Spread {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Spread {
+    pub fn of(samples: impl IntoIterator<Item = Duration>) -> Spread {
+        let mut samples = samples.into_iter().collect::<Vec<_>>();
+        assert!(!samples.is_empty());
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) { (samples[mid - 1] + samples[mid]) / 2 } else { samples[mid] };
+        Spread { min: samples[0], median, max: *samples.last().unwrap() }
+    }
+}
@@ -0,0 +1,187 @@
+//! Sets up the files for a new day: the solver module, its registration with the runner, empty
+//! input and example files, and a test checking the example answers.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use crate::examples;
+use crate::runner::INPUT_DIR;
+
+fn module_name(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+fn type_name(day: u32) -> String {
+    format!("Day{day:02}")
+}
+
+pub fn module_source(day: u32) -> String {
+    format!(r#"use crate::solver::{{input_lines, PartAnswer, Solver}};
+
+pub struct {ty};
+
+impl Solver for {ty} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Vec<String> {{
+        input_lines(input).map(str::to_string).collect()
+    }}
+
+    fn part1(_lines: &Vec<String>) -> PartAnswer {{
+        Ok(String::new())
+    }}
+
+    fn part2(_lines: &Vec<String>) -> Option<PartAnswer> {{
+        Some(Ok(String::new()))
+    }}
+}}
+"#, ty = type_name(day))
+}
+
+/// The test fails until an example answer for the day is recorded in the manifest.
+pub fn test_source(day: u32) -> String {
+    format!(r#"mod support;
+
+#[test]
+fn examples() {{
+    support::check_examples({day});
+}}
+"#)
+}
+
+fn already_exists(what: String) -> io::Error {
+    io::Error::new(io::ErrorKind::AlreadyExists, what)
+}
+
+fn unexpected(what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, what)
+}
+
+/// Adds `pub mod dayNN;` to the module list of `lib.rs`, keeping it sorted.
+pub fn add_module(lib_rs: &str, day: u32) -> io::Result<String> {
+    let new_line = format!("pub mod {};", module_name(day));
+    let mut lines = lib_rs.lines().collect::<Vec<_>>();
+    if lines.contains(&new_line.as_str()) {
+        return Err(already_exists(format!("{new_line} is already in lib.rs")));
+    }
+    let pos = lines.iter().position(|line| line.starts_with("pub mod ") && *line > new_line.as_str())
+        .or_else(|| lines.iter().rposition(|line| line.starts_with("pub mod ")).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(pos, &new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the `use crate::{...}` import and the `DAYS` table of `runner.rs`.
+pub fn register_day(runner_rs: &str, day: u32) -> io::Result<String> {
+    let module = module_name(day);
+    let entry = format!("    Day::new::<{module}::{}>({day}),", type_name(day));
+    let entry_day = |line: &str| -> Option<u32> {
+        line.trim().strip_prefix("Day::new::<")?.split_once(">(")?.1.strip_suffix("),")?.parse().ok()
+    };
+
+    let mut lines = runner_rs.lines().map(str::to_string).collect::<Vec<_>>();
+    let import = lines.iter_mut().find(|line| line.starts_with("use crate::{day")).ok_or_else(|| unexpected("No import of the day modules in runner.rs"))?;
+    let mut modules = import.strip_prefix("use crate::{").and_then(|l| l.strip_suffix("};"))
+        .ok_or_else(|| unexpected("Day modules aren't imported on a single line in runner.rs"))?
+        .split(", ").map(str::to_string).collect::<Vec<_>>();
+    if modules.contains(&module) {
+        return Err(already_exists(format!("{module} is already registered in runner.rs")));
+    }
+    modules.push(module);
+    modules.sort();
+    *import = format!("use crate::{{{}}};", modules.join(", "));
+
+    let start = lines.iter().position(|line| line.starts_with("pub const DAYS")).ok_or_else(|| unexpected("No DAYS table in runner.rs"))?;
+    let end = start + lines[start..].iter().position(|line| line == "];").ok_or_else(|| unexpected("Unterminated DAYS table in runner.rs"))?;
+    let pos = (start + 1..end).find(|&i| entry_day(&lines[i]).is_some_and(|d| d > day)).unwrap_or(end);
+    lines.insert(pos, entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn create_new(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new().write(true).create_new(true).open(path)?.write_all(contents.as_bytes())
+}
+
+/// Creates and registers everything for `day`, relative to the repository root. Returns the paths
+/// that were created or changed.
+///
+/// Everything is checked before anything is written, and if writing fails partway, the files
+/// written so far are removed or restored, along with the directories created for them.
+pub fn new_day(root: &Path, day: u32) -> io::Result<Vec<PathBuf>> {
+    let module = module_name(day);
+    let mut created = vec![
+        (root.join(format!("src/{module}.rs")), module_source(day)),
+        (root.join(format!("tests/{module}.rs")), test_source(day)),
+    ];
+    if let Some((path, _)) = created.iter().find(|(path, _)| path.exists()) {
+        return Err(already_exists(format!("{} already exists", path.display())));
+    }
+    // The input may well have been downloaded before the day was set up
+    for path in [root.join(examples::DIR).join(examples::file_name(day)), root.join(INPUT_DIR).join(format!("{module}.txt"))] {
+        if !path.exists() {
+            created.push((path, String::new()));
+        }
+    }
+
+    let lib_path = root.join("src/lib.rs");
+    let runner_path = root.join("src/runner.rs");
+    let manifest_path = root.join(examples::MANIFEST);
+    let lib_rs = fs::read_to_string(&lib_path)?;
+    let runner_rs = fs::read_to_string(&runner_path)?;
+    let manifest = match fs::read_to_string(&manifest_path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        manifest => Some(manifest?),
+    };
+    let mut changed = vec![
+        (lib_path, Some(lib_rs.clone()), add_module(&lib_rs, day)?),
+        (runner_path, Some(runner_rs.clone()), register_day(&runner_rs, day)?),
+    ];
+    let file_name = examples::file_name(day);
+    let mut new_manifest = manifest.clone().unwrap_or_default();
+    if !new_manifest.is_empty() && !new_manifest.ends_with('\n') {
+        new_manifest.push('\n');
+    }
+    new_manifest += &format!("# {file_name} 1 <answer>\n# {file_name} 2 <answer>\n");
+    changed.push((manifest_path, manifest, new_manifest));
+
+    // Directories that writing creates, outermost first, to remove them again if it fails
+    let mut new_dirs: Vec<PathBuf> = Vec::new();
+    for (path, _) in &created {
+        for dir in path.ancestors().skip(1).take_while(|dir| !dir.exists()) {
+            if !new_dirs.iter().any(|d| d == dir) {
+                new_dirs.push(dir.to_path_buf());
+            }
+        }
+    }
+    new_dirs.sort_by_key(|dir| dir.components().count());
+
+    let mut written = 0;
+    let result = created.iter().try_for_each(|(path, contents)| {
+        create_new(path, contents)?;
+        written += 1;
+        Ok(())
+    }).and_then(|()| changed.iter().try_for_each(|(path, _, contents)| fs::write(path, contents)));
+    if let Err(e) = result {
+        // Best effort, the original error is the one worth reporting
+        for (path, _) in &created[..written] {
+            let _ = fs::remove_file(path);
+        }
+        for (path, original, _) in &changed {
+            let _ = match original {
+                Some(original) => fs::write(path, original),
+                None => fs::remove_file(path),
+            };
+        }
+        for dir in new_dirs.iter().rev() {
+            let _ = fs::remove_dir(dir);
+        }
+        return Err(e);
+    }
+
+    let mut paths = created.into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+    paths.extend(changed.into_iter().map(|(path, _, _)| path));
+    Ok(paths)
+}
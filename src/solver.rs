@@ -0,0 +1,56 @@
+use std::fmt::{self, Display, Formatter};
+use crate::cancel::Cancelled;
+
+/// Why a part has no answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PartError {
+    /// Its search was stopped before finishing.
+    Cancelled(Cancelled),
+    /// The input has no answer, such as a problem that can't be evaluated.
+    Unsolvable(String),
+}
+
+impl From<Cancelled> for PartError {
+    fn from(cancelled: Cancelled) -> Self {
+        PartError::Cancelled(cancelled)
+    }
+}
+
+impl Display for PartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PartError::Cancelled(cancelled) => cancelled.fmt(f),
+            PartError::Unsolvable(reason) => f.write_str(reason),
+        }
+    }
+}
+
+/// A part's answer, or why there is none.
+pub type PartAnswer = Result<String, PartError>;
+
+/// A day's puzzle, split into phases so that the runner can time them separately.
+pub trait Solver {
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> PartAnswer;
+    /// `None` for days with a single part.
+    fn part2(_input: &Self::Input) -> Option<PartAnswer> {
+        None
+    }
+
+    /// Per-record detail behind the part 1 answer, e.g. one value per input line. Only computed
+    /// on request, so it may redo the work of [`Solver::part1`].
+    fn part1_records(_input: &Self::Input) -> Option<Vec<String>> {
+        None
+    }
+
+    fn part2_records(_input: &Self::Input) -> Option<Vec<String>> {
+        None
+    }
+}
+
+/// Lines up to the first empty one, like the binaries reading stdin.
+pub fn input_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().take_while(|line| !line.is_empty())
+}
@@ -0,0 +1,48 @@
+use std::hint::black_box;
+use aoc_2025::alloc::{self, measure, AllocStats, CountingAllocator};
+use aoc_2025::runner::DAYS;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn measures_allocations_of_closure() {
+    alloc::enable();
+    let (_, stats) = measure(|| {
+        let a = black_box(vec![0u8; 1000]);
+        let b = black_box(vec![0u8; 500]);
+        drop((a, b));
+        black_box(vec![0u8; 200]);
+    });
+    assert_eq!(stats, AllocStats { peak: 1500, count: 3, bytes: 1700 });
+
+    let (kept, stats) = measure(|| black_box(Vec::<u64>::with_capacity(16)));
+    assert_eq!(stats.peak, 128);
+    drop(kept);
+
+    let day1 = DAYS.iter().find(|d| d.day == 1).unwrap();
+    let (_, timings) = day1.solve("L10\nR20\n");
+    assert!(timings.memory.parse.count > 0);
+    assert!(timings.memory.part2.is_some());
+}
+
+#[test]
+fn outer_measurement_covers_nested_ones() {
+    alloc::enable();
+    let (_, outer) = measure(|| {
+        let kept = black_box(vec![0u8; 1000]);
+        let (_, inner) = measure(|| black_box(vec![0u8; 500]));
+        assert_eq!(inner.peak, 500);
+        drop(kept);
+        let (_, inner) = measure(|| black_box(vec![0u8; 200]));
+        assert_eq!(inner.peak, 200);
+    });
+    assert_eq!(outer, AllocStats { peak: 1500, count: 3, bytes: 1700 });
+
+    // The parsed input is still held while the parts run
+    let day5 = DAYS.iter().find(|d| d.day == 5).unwrap();
+    let (_, timings) = day5.solve("3-5\n10-14\n\n1\n5\n");
+    let memory = timings.memory;
+    assert!(memory.overall.peak > memory.parse.peak.max(memory.part1.peak));
+    assert_eq!(memory.overall.count, memory.parse.count + memory.part1.count + memory.part2.unwrap().count);
+}
@@ -0,0 +1,43 @@
+use std::io;
+use aoc_2025::animation::{write_removal_html, AnimationOptions, Crop};
+use aoc_2025::automaton::Grid;
+use aoc_2025::day04::{remove_rolls, Semantics};
+
+fn rolls(rows: &[&str]) -> Grid<bool> {
+    Grid::from_rows(rows.iter().map(|row| row.chars().map(|c| c == '@').collect()))
+}
+
+fn render(grid: &Grid<bool>, options: &AnimationOptions) -> io::Result<String> {
+    let removal = remove_rolls(grid, Semantics::Synchronous);
+    let mut out = Vec::new();
+    write_removal_html(&mut out, grid, &removal.removed_in, options)?;
+    Ok(String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn html_lists_removal_generation_of_each_cell() {
+    // The corners go first, the middle column once they're gone
+    let grid = rolls(&["@@@.", "@@@.", "...."]);
+    let html = render(&grid, &AnimationOptions::default()).unwrap();
+    assert!(html.contains("<canvas id=\"c\" width=\"16\" height=\"12\"></canvas>"));
+    assert!(html.contains("const W = 4, H = 3, PX = 4, MS = 200;"));
+    assert!(html.contains("const cells = [0,1,0,-1,0,1,0,-1,-1,-1,-1,-1];"));
+    assert!(html.contains("const frames = [0,1,2];"));
+
+    let options = AnimationOptions { frame_step: 2, crop: Some(Crop { x: 1, y: 0, width: 2, height: 2 }), ..AnimationOptions::default() };
+    let html = render(&grid, &options).unwrap();
+    assert!(html.contains("const cells = [1,0,1,0];"));
+    assert!(html.contains("const frames = [0,2];"));
+}
+
+#[test]
+fn invalid_options_are_errors() {
+    let grid = rolls(&["@@", "@@"]);
+    let too_wide = AnimationOptions { crop: Some(Crop { x: 1, y: 0, width: 2, height: 1 }), ..AnimationOptions::default() };
+    let overflowing = AnimationOptions { crop: Some(Crop { x: usize::MAX, y: 0, width: 2, height: 1 }), ..AnimationOptions::default() };
+    let huge_cells = AnimationOptions { cell_pixels: u32::MAX, ..AnimationOptions::default() };
+    let no_step = AnimationOptions { frame_step: 0, ..AnimationOptions::default() };
+    for options in [too_wide, overflowing, huge_cells, no_step] {
+        assert_eq!(render(&grid, &options).unwrap_err().kind(), io::ErrorKind::InvalidInput, "{options:?}");
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_2025::answers::{Assessment, RecordedAnswers, Verdict};
+
+#[test]
+fn lines_without_verdict_are_correct() {
+    let recorded = RecordedAnswers::parse("# day part answer\n1 1 42\n\n1 2 some text\n2 1 wrong 7\n").unwrap();
+    assert_eq!(recorded.get(1, 1), Some("42"));
+    assert_eq!(recorded.get(1, 2), Some("some text"));
+    assert_eq!(recorded.get(2, 1), None);
+    assert_eq!(recorded.attempts(2, 1)[0].verdict, Verdict::Wrong);
+}
+
+#[test]
+fn answers_are_checked_against_bounds() {
+    let recorded = RecordedAnswers::parse("3 1 too-low 100\n3 1 too-high 2000\n3 1 wrong 500\n").unwrap();
+    let assess = |answer| recorded.assess(3, 1, answer);
+    assert_eq!(assess("500"), Assessment::KnownWrong(Verdict::Wrong));
+    assert_eq!(assess("99"), Assessment::TooLow { bound: "100".to_string() });
+    assert_eq!(assess("12345"), Assessment::TooHigh { bound: "2000".to_string() });
+    assert_eq!(assess("1999"), Assessment::Unchecked);
+    assert_eq!(assess("not a number"), Assessment::Unchecked);
+}
+
+#[test]
+fn correct_answer_overrides_bounds() {
+    let recorded = RecordedAnswers::parse("3 2 too-high 50\n3 2 correct 42\n").unwrap();
+    assert_eq!(recorded.assess(3, 2, "42"), Assessment::Verified);
+    assert_eq!(recorded.assess(3, 2, "43"), Assessment::Wrong { expected: "42".to_string() });
+    assert_eq!(recorded.assess(3, 1, "42"), Assessment::Unchecked);
+}
+
+#[test]
+fn malformed_lines_are_errors() {
+    let error = RecordedAnswers::parse("1 1 42\n# comment\nx 1 42\n").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().starts_with("Line 3: "));
+    assert!(RecordedAnswers::parse("1 2\n").is_err());
+}
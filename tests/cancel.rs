@@ -0,0 +1,45 @@
+use std::time::Duration;
+use aoc_2025::cancel::{Cancel, Cancelled};
+use aoc_2025::day10::{min_presses_joltage, min_presses_joltage_search, Day10};
+use aoc_2025::day12::Day12;
+use aoc_2025::generate::{generate, Size};
+use aoc_2025::runner::find_day;
+use aoc_2025::solver::{PartError, Solver};
+
+#[test]
+fn cancelled_reports_innermost_record_and_bound() {
+    let cancelled = Cancel::with_timeout(Duration::ZERO).check().unwrap_err()
+        .at_record("machine", 36).with_best(12)
+        .at_record("day", 0).with_best(99);
+    assert_eq!(cancelled.to_string(), "timed out at machine #37, best bound so far 12");
+
+    let token = Cancel::never();
+    assert!(token.check().is_ok());
+    token.clone().cancel();
+    assert_eq!(token.check(), Err(Cancelled { timed_out: false, record: None, best: None }));
+}
+
+#[test]
+fn joltage_search_agrees_with_solver_and_stops_when_cancelled() {
+    let machines = Day10::parse(&generate(10, 1, &Size { records: Some(20), width: None }).unwrap());
+    for m in &machines {
+        assert_eq!(min_presses_joltage_search(m, &Cancel::never()), Ok(min_presses_joltage(m)));
+        assert!(min_presses_joltage_search(m, &Cancel::with_timeout(Duration::ZERO)).unwrap_err().timed_out);
+    }
+}
+
+#[test]
+fn installed_token_stops_solver_at_first_record() {
+    let day10 = find_day(10).unwrap();
+    let input = generate(10, 1, &Size { records: Some(5), width: None }).unwrap();
+    let (answers, _) = Cancel::with_timeout(Duration::ZERO).install(|| day10.solve(&input));
+    assert!(answers.part2.unwrap().unwrap_err().starts_with("timed out at machine #1"));
+    // Only the run it was installed for is cancelled
+    assert!(day10.solve(&input).0.part2.unwrap().is_ok());
+
+    // Solvers hand the token's error back as their answer
+    let presents = Day12::parse(&generate(12, 1, &Size { records: Some(3), width: None }).unwrap());
+    let answer = Cancel::with_timeout(Duration::ZERO).install(|| Day12::part1(&presents));
+    let Err(PartError::Cancelled(cancelled)) = answer else { panic!("{answer:?} isn't cancelled") };
+    assert_eq!(cancelled.record, Some(("region", 1)));
+}
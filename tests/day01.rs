@@ -0,0 +1,18 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::dial::Dial;
+use support::{check, vec_of};
+
+#[test]
+fn rotate_matches_click_by_click() {
+    let strategy = (1..=20i64, -30..=30i64, vec_of(-30..=30i64, 1..=3), vec_of(-60..=60i64, 1..=10));
+    check(strategy, |(size, start, targets, movements)| {
+        let mut dial = Dial::new(*size, *start, targets);
+        let mut reference = dial.clone();
+        for &movement in movements {
+            prop_assert_eq!(dial.rotate(movement), reference.rotate_clicks(movement));
+        }
+        Ok(())
+    });
+}
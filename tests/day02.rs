@@ -0,0 +1,36 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::day02::{advance_to_next_double, advance_to_next_double_naive, sum_repeated, sum_repeated_naive};
+use aoc_2025::integer::BigUint;
+use support::check;
+
+#[test]
+fn advance_to_next_double_matches_naive() {
+    check((1..=99_999u64, 2..=5u32), |&(val, n_groups)| {
+        // Keep the answer small enough to find by counting up
+        prop_assume!((val.ilog10() + 1).next_multiple_of(n_groups) <= 5);
+        prop_assert_eq!(advance_to_next_double(val, n_groups), advance_to_next_double_naive(val, n_groups));
+        Ok(())
+    });
+}
+
+#[test]
+fn sum_repeated_matches_naive() {
+    check((0..=2_000_000u64, 0..=20_000u64), |&(start, len)| {
+        let expected = sum_repeated_naive(start..=start + len);
+        prop_assert_eq!(sum_repeated(u128::from(start)..=u128::from(start + len)).map(|s| (s.any_groups, s.two_groups)), Some((expected.any_groups, expected.two_groups)));
+        Ok(())
+    });
+}
+
+#[test]
+fn sum_repeated_big_matches_fixed_width() {
+    check((0..=u64::MAX, 0..=u64::MAX), |&(a, b)| {
+        let fixed = sum_repeated(u128::from(a)..=u128::from(b)).unwrap();
+        let big = sum_repeated(BigUint::from(a)..=BigUint::from(b)).unwrap();
+        prop_assert_eq!(fixed.any_groups.to_string(), big.any_groups.to_string());
+        prop_assert_eq!(fixed.two_groups.to_string(), big.two_groups.to_string());
+        Ok(())
+    });
+}
@@ -0,0 +1,14 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::day03::{max_joltage, max_joltage_naive};
+use support::{check, vec_of};
+
+#[test]
+fn max_joltage_matches_naive() {
+    check((vec_of(1..=9u8, 1..=12), 1..=12usize), |(bank, num_batteries)| {
+        prop_assume!(*num_batteries <= bank.len());
+        prop_assert_eq!(max_joltage::<u64>(bank, *num_batteries), Some(max_joltage_naive(bank, *num_batteries)));
+        Ok(())
+    });
+}
@@ -0,0 +1,45 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::automaton::{moore_neighbourhood, Automaton, Grid, Update};
+use aoc_2025::day04::{remove_rolls, remove_rolls_naive, Semantics};
+use support::{check, vec_of};
+
+fn make_grid(width: usize, cells: &[u8]) -> Grid<bool> {
+    Grid::from_rows(cells.chunks_exact(width).map(|row| row.iter().map(|c| *c != 0).collect()))
+}
+
+#[test]
+fn remove_rolls_matches_naive() {
+    check((1..=8usize, vec_of(0..=1u8, 0..=64)), |(width, cells)| {
+        let grid = make_grid(*width, cells);
+        for semantics in [Semantics::Synchronous, Semantics::Greedy] {
+            prop_assert_eq!(remove_rolls(&grid, semantics).removed_per_generation, remove_rolls_naive(&grid, semantics));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+fn automaton_only_reexamines_cells_near_changes() {
+    check((1..=8usize, vec_of(0..=1u8, 0..=64)), |(width, cells)| {
+        let grid = make_grid(*width, cells);
+        for update in [Update::Synchronous, Update::InPlace] {
+            let neighbourhood = moore_neighbourhood(grid.width(), grid.height());
+            let mut automaton = Automaton::new(grid.clone(), update, neighbourhood, |&roll, neighbours| {
+                (roll && neighbours.filter(|&&n| n).count() < 4).then_some(false)
+            });
+            automaton.record_snapshots();
+            automaton.run();
+            let history = automaton.history();
+            let num_cells = grid.width() * grid.height();
+            prop_assert_eq!(history[0].examined, num_cells);
+            // Each change queues at most the cell and its 8 neighbours
+            let changed = history.iter().map(|s| s.changed).sum::<usize>();
+            prop_assert_eq!(history.iter().map(|s| s.examined).sum::<usize>() <= num_cells + 9 * changed, true);
+            prop_assert_eq!(history.last().unwrap().changed, 0);
+            prop_assert_eq!(automaton.snapshots().len(), history.len() + 1);
+        }
+        Ok(())
+    });
+}
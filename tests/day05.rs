@@ -0,0 +1,102 @@
+#[macro_use]
+mod support;
+
+use std::collections::BTreeSet;
+use std::io;
+use aoc_2025::day05::{count_fresh_streaming, freshness_report, freshness_report_naive, Day05, NumberedRange};
+use aoc_2025::interval_set::IntervalSet;
+use aoc_2025::solver::Solver;
+use support::{check, vec_of};
+
+fn to_ranges(pairs: &[(u64, u64)]) -> Vec<NumberedRange> {
+    pairs.iter().enumerate()
+        .map(|(i, &(start, len))| NumberedRange { line: i + 1, range: start..=start + len })
+        .collect()
+}
+
+#[test]
+fn interval_set_matches_naive() {
+    // (is insert, start, len)
+    let ops = vec_of((0..=1u8, 0..=60u64, 0..=10u64), 0..=20);
+    check((ops.clone(), ops), |(ops, other_ops)| {
+        let build = |ops: &[(u8, u64, u64)]| {
+            let mut set = IntervalSet::new();
+            let mut reference = BTreeSet::new();
+            for &(is_insert, start, len) in ops {
+                if is_insert == 1 {
+                    set.insert(start..=start + len);
+                    reference.extend(start..=start + len);
+                } else {
+                    set.remove(start..=start + len);
+                    reference.retain(|x| !(start..=start + len).contains(x));
+                }
+            }
+            (set, reference)
+        };
+        let to_values = |set: &IntervalSet<u64>| set.iter().flatten().collect::<BTreeSet<_>>();
+
+        let (set, reference) = build(ops);
+        let (other, other_reference) = build(other_ops);
+        prop_assert_eq!(to_values(&set), reference);
+        prop_assert_eq!(set.covered_len(), reference.len() as u128);
+        // Intervals must be kept merged
+        prop_assert_eq!(set.num_intervals(), reference.iter().filter(|x| **x == 0 || !reference.contains(&(**x - 1))).count());
+        for x in 0..=80 {
+            prop_assert_eq!(set.contains(x), reference.contains(&x));
+        }
+        prop_assert_eq!(to_values(&set.union(&other)), &reference | &other_reference);
+        prop_assert_eq!(to_values(&set.intersection(&other)), &reference & &other_reference);
+        prop_assert_eq!(to_values(&set.difference(&other)), &reference - &other_reference);
+        Ok(())
+    });
+}
+
+#[test]
+fn interval_set_handles_max_bound() {
+    check(vec_of((0..=1u8, 0..=20u64), 1..=10), |ops| {
+        // Intervals counted back from u64::MAX
+        let mut set = IntervalSet::new();
+        let mut reference = BTreeSet::new();
+        for &(is_insert, offset) in ops {
+            let range = u64::MAX - offset..=u64::MAX;
+            if is_insert == 1 {
+                set.insert(range.clone());
+                reference.extend(range);
+            } else {
+                set.remove(range.clone());
+                reference.retain(|x| !range.contains(x));
+            }
+        }
+        prop_assert_eq!(set.iter().flatten().collect::<BTreeSet<_>>(), reference);
+        Ok(())
+    });
+}
+
+#[test]
+fn freshness_report_matches_naive() {
+    check((vec_of((0..=50u64, 0..=10u64), 0..=8), vec_of(0..=60u64, 0..=10)), |(pairs, ids)| {
+        let ranges = to_ranges(pairs);
+        prop_assert_eq!(freshness_report(&ranges, ids), freshness_report_naive(&ranges, ids));
+        Ok(())
+    });
+}
+
+#[test]
+fn streaming_count_matches_solver() {
+    check((vec_of((0..=50u64, 0..=10u64), 0..=8), vec_of(0..=60u64, 0..=10)), |(pairs, ids)| {
+        let ranges = to_ranges(pairs);
+        let range_lines = ranges.iter().map(|r| format!("{}-{}\n", r.range.start(), r.range.end())).collect::<String>();
+        let id_lines = ids.iter().map(|id| format!("{id}\n")).collect::<String>();
+        let mut sorted = ids.clone();
+        sorted.sort_unstable();
+        let sorted_lines = sorted.iter().map(|id| format!("{id}\n")).collect::<String>();
+        let fresh_ranges: IntervalSet<u64> = ranges.into_iter().map(|r| r.range).collect();
+        let streamed = count_fresh_streaming(&fresh_ranges, sorted_lines.as_bytes()).unwrap();
+        // Ids listed more than once count once in both
+        prop_assert_eq!(Ok(streamed.to_string()), Day05::part1(&Day05::parse(&format!("{range_lines}\n{id_lines}"))));
+        if sorted != *ids {
+            prop_assert_eq!(count_fresh_streaming(&fresh_ranges, id_lines.as_bytes()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        }
+        Ok(())
+    });
+}
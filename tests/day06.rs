@@ -0,0 +1,154 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::day06::{Day06, ErrorKind, Operators, Reading, Worksheet};
+use aoc_2025::generate::{generate, Size};
+use aoc_2025::integer::BigUint;
+use aoc_2025::solver::Solver;
+use support::{check, vec_of};
+
+fn sorted_digits(operands: &[String]) -> Vec<char> {
+    let mut digits = operands.concat().chars().collect::<Vec<_>>();
+    digits.sort_unstable();
+    digits
+}
+
+#[test]
+fn row_operands_match_whitespace_split() {
+    check((0..=u64::MAX, 1..=20usize, 1..=5usize), |&(seed, problems, operands)| {
+        let input = generate(6, seed, &Size { records: Some(problems), width: Some(operands) }).unwrap();
+        let lines = input.lines().collect::<Vec<_>>();
+        let worksheet = Worksheet::parse(&lines);
+        prop_assert_eq!(worksheet.problems.len(), problems);
+        for (i, problem) in worksheet.problems.iter().enumerate() {
+            let expected = lines[..operands].iter().map(|l| l.split_ascii_whitespace().nth(i).unwrap()).collect::<Vec<_>>();
+            prop_assert_eq!(problem.operands(Reading::Rows), expected);
+        }
+        Ok(())
+    });
+}
+
+#[test]
+fn both_readings_use_the_same_digits() {
+    check((0..=u64::MAX, 1..=20usize, 1..=5usize), |&(seed, problems, operands)| {
+        let input = generate(6, seed, &Size { records: Some(problems), width: Some(operands) }).unwrap();
+        let worksheet = Worksheet::parse(&input.lines().collect::<Vec<_>>());
+        for problem in &worksheet.problems {
+            prop_assert_eq!(sorted_digits(&problem.operands(Reading::Rows)), sorted_digits(&problem.operands(Reading::Columns)));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+fn fixed_width_overflows_only_when_big_result_is_too_large() {
+    check((0..=u64::MAX, 1..=10usize, 1..=8usize), |&(seed, problems, operands)| {
+        let input = generate(6, seed, &Size { records: Some(problems), width: Some(operands) }).unwrap();
+        let worksheet = Worksheet::parse(&input.lines().collect::<Vec<_>>());
+        let fixed = worksheet.evaluate(Reading::Columns, &Operators::<u64>::standard());
+        let big = worksheet.evaluate(Reading::Columns, &Operators::<BigUint>::standard());
+        for (fixed, big) in fixed.iter().zip(big) {
+            let big = big.unwrap();
+            match fixed {
+                Ok(fixed) => prop_assert_eq!(fixed.to_string(), big.to_string()),
+                Err(e) => {
+                    prop_assert_eq!(e.kind, ErrorKind::Overflow);
+                    prop_assert_eq!(big > BigUint::from(u64::MAX), true);
+                }
+            }
+        }
+        Ok(())
+    });
+}
+
+/// Totals computed straight from the lines, scanning the columns from the right and applying a
+/// problem's operator when the blank column before it is reached.
+fn column_totals_naive(lines: &[&str]) -> u128 {
+    let width = lines.iter().map(|l| l.len()).max().unwrap();
+    let (operator_row, rows) = lines.split_last().unwrap();
+    let at = |line: &str, x: usize| line.as_bytes().get(x).copied().unwrap_or(b' ');
+    let mut total = 0;
+    let mut operands = Vec::new();
+    let mut operator = None;
+    for x in (0..=width).rev() {
+        let blank = x == 0 || lines.iter().all(|l| at(l, x - 1) == b' ');
+        if blank {
+            if let Some(op) = operator.take() {
+                total += operands.drain(..).reduce(|a, b| if op == b'+' { a + b } else { a * b }).unwrap();
+            }
+            continue;
+        }
+        let digits = rows.iter().map(|l| at(l, x - 1)).filter(u8::is_ascii_digit).collect::<Vec<_>>();
+        if !digits.is_empty() {
+            operands.push(str::from_utf8(&digits).unwrap().parse::<u128>().unwrap());
+        }
+        if at(operator_row, x - 1) != b' ' {
+            operator = Some(at(operator_row, x - 1));
+        }
+    }
+    total
+}
+
+#[test]
+fn totals_match_naive() {
+    check((0..=u64::MAX, 1..=20usize, 1..=4usize), |&(seed, problems, operands)| {
+        let input = generate(6, seed, &Size { records: Some(problems), width: Some(operands) }).unwrap();
+        let lines = input.lines().collect::<Vec<_>>();
+        let worksheet = Day06::parse(&input);
+        let row_totals = (0..problems).map(|i| {
+            let mut column = lines.iter().map(|l| l.split_ascii_whitespace().nth(i).unwrap());
+            let operator = column.next_back().unwrap();
+            column.map(|d| d.parse::<u128>().unwrap()).reduce(|a, b| if operator == "+" { a + b } else { a * b }).unwrap()
+        }).sum::<u128>();
+        prop_assert_eq!(Day06::part1(&worksheet), Ok(row_totals.to_string()));
+        prop_assert_eq!(Day06::part2(&worksheet), Some(Ok(column_totals_naive(&lines).to_string())));
+        Ok(())
+    });
+}
+
+#[test]
+fn defined_operators_fold_their_expression() {
+    check(vec_of(0..=1000u64, 0..=6), |operands| {
+        let mut operators = Operators::<u64>::standard();
+        operators.define("%", "a b a b / * -").unwrap();
+        operators.define("avg2", "a b + 2 /").unwrap();
+        operators.define("low", "min").unwrap();
+        prop_assume!(!operands.is_empty());
+        // One operand per row, above the operator
+        let evaluate = |symbol: &str| {
+            let lines = operands.iter().map(u64::to_string).chain([symbol.to_string()]).collect::<Vec<_>>();
+            Worksheet::parse(&lines).problems[0].evaluate(Reading::Rows, &operators)
+        };
+        let (&first, rest) = operands.split_first().unwrap();
+        let remainder = rest.iter().try_fold(first, |a, &b| a.checked_rem(b));
+        prop_assert_eq!(evaluate("%").ok(), remainder);
+        prop_assert_eq!(evaluate("avg2").ok(), Some(rest.iter().fold(first, |a, &b| (a + b) / 2)));
+        prop_assert_eq!(evaluate("low").ok(), operands.iter().min().copied());
+        Ok(())
+    });
+}
+
+#[test]
+fn invalid_definitions_are_rejected() {
+    let mut operators = Operators::<u64>::standard();
+    assert!(operators.define("x", "a +").unwrap_err().contains("needs two values"));
+    assert!(operators.define("x", "a b").unwrap_err().contains("exactly one value"));
+    assert!(operators.define("x", "a b ^").unwrap_err().contains("Unknown token '^'"));
+    assert!(!operators.symbols().any(|s| s == "x"));
+}
+
+#[test]
+fn problems_that_cant_be_evaluated_fail_the_part() {
+    let worksheet = Day06::parse("12 3\n4445\n+   \n");
+    let errors = worksheet.evaluate(Reading::Rows, &Operators::<u64>::standard()).into_iter().map(|r| r.unwrap_err().kind).collect::<Vec<_>>();
+    assert_eq!(errors, [ErrorKind::SplitOperand]);
+    assert!(Day06::part1(&worksheet).is_err());
+
+    // A column's digits may have gaps between them
+    assert_eq!(Day06::part2(&worksheet), Some(Ok("77".to_string())));
+
+    let worksheet = Day06::parse("1 5\n3 0\n- /\n");
+    let errors = worksheet.evaluate(Reading::Rows, &Operators::<u64>::standard()).into_iter().map(|r| r.unwrap_err().kind).collect::<Vec<_>>();
+    assert_eq!(errors, [ErrorKind::Negative, ErrorKind::DivisionByZero]);
+    assert_eq!(Day06::part1(&worksheet).unwrap_err().to_string(), "negative result in problem at column 0 ('-')");
+}
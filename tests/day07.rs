@@ -0,0 +1,54 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::day07::{Boundary, Manifold, Rules};
+use support::{check, vec_of};
+
+const CELLS: [char; 5] = ['.', '^', '/', '\\', '#'];
+const BOUNDARIES: [Boundary; 3] = [Boundary::Lost, Boundary::Wrap, Boundary::Reflect];
+
+fn make_manifold(width: usize, start_x: usize, cells: &[usize]) -> Manifold {
+    let mut lines = vec![".".repeat(width)];
+    lines[0].replace_range(start_x % width..start_x % width + 1, "S");
+    lines.extend(cells.chunks_exact(width).map(|row| row.iter().map(|&c| CELLS[c]).collect::<String>()));
+    Manifold::parse(&lines)
+}
+
+#[test]
+fn count_splits_matches_naive() {
+    check((1..=6usize, 0..=5usize, 0..=2usize, vec_of(0..=4usize, 0..=48)), |(width, start_x, boundary, cells)| {
+        let manifold = make_manifold(*width, *start_x, cells);
+        let rules = Rules { boundary: BOUNDARIES[*boundary] };
+        prop_assert_eq!(manifold.count_splits(&rules), manifold.count_splits_naive(&rules));
+        Ok(())
+    });
+}
+
+#[test]
+fn count_timelines_matches_naive() {
+    check((1..=6usize, 0..=5usize, 0..=2usize, vec_of(0..=4usize, 0..=48)), |(width, start_x, boundary, cells)| {
+        let manifold = make_manifold(*width, *start_x, cells);
+        let rules = Rules { boundary: BOUNDARIES[*boundary] };
+        prop_assert_eq!(manifold.count_timelines(&rules), manifold.count_timelines_naive(&rules));
+        Ok(())
+    });
+}
+
+#[test]
+fn deflected_beams_continue_from_row_below() {
+    // The splitter beside the first one is on the same row, so the split beams pass it by
+    let side_by_side = Manifold::parse(&["..S..", "..^^.", "....."]);
+    assert_eq!(side_by_side.count_splits(&Rules::default()), 1);
+    assert_eq!(side_by_side.count_timelines(&Rules::default()), 2);
+
+    // One row down it's in the way of the right beam
+    let diagonal = Manifold::parse(&["..S..", "..^..", "...^.", "....."]);
+    assert_eq!(diagonal.count_splits(&Rules::default()), 2);
+    assert_eq!(diagonal.count_timelines(&Rules::default()), 3);
+
+    // A beam reflected back into the splitter's column goes on below it instead of hitting it again
+    let reflect = Rules { boundary: Boundary::Reflect };
+    let at_edge = Manifold::parse(&["S..", "^..", "...", "^.."]);
+    assert_eq!(at_edge.count_splits(&reflect), 2);
+    assert_eq!(at_edge.count_timelines(&reflect), 3);
+}
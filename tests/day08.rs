@@ -0,0 +1,86 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::day08::{connect_closest, final_connection, largest_sets_product, sqr_distance};
+use support::{check, vec_of, Strategy};
+
+/// Sizes of the connected components when every pair in `edges` is connected, found by flooding
+/// from each point in turn. Largest first.
+fn component_sizes_naive(num_points: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut seen = vec![false; num_points];
+    let mut sizes = Vec::new();
+    for start in 0..num_points {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut stack = vec![start];
+        let mut size = 0;
+        while let Some(i) = stack.pop() {
+            size += 1;
+            for &(a, b) in edges {
+                for (from, to) in [(a, b), (b, a)] {
+                    if from == i && !seen[to] {
+                        seen[to] = true;
+                        stack.push(to);
+                    }
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes
+}
+
+/// Every pair of points with its squared distance, closest first.
+fn sorted_pairs(points: &[[i64; 3]]) -> Vec<(i64, (usize, usize))> {
+    let mut pairs = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            pairs.push((sqr_distance(&points[i], &points[j]), (i, j)));
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+fn points_strategy() -> impl Strategy<Value = Vec<(i64, i64, i64)>> {
+    vec_of((-20..=20i64, -20..=20i64, -20..=20i64), 1..=12)
+}
+
+#[test]
+fn closest_connections_match_naive() {
+    check((points_strategy(), 0..=30usize), |(points, num_connections)| {
+        let points = points.iter().map(|&(x, y, z)| [x, y, z]).collect::<Vec<_>>();
+        let pairs = sorted_pairs(&points);
+        let k = (*num_connections).min(pairs.len());
+        // Which of several equally distant pairs gets connected is unspecified
+        prop_assume!(k == 0 || k == pairs.len() || pairs[k - 1].0 != pairs[k].0);
+
+        let edges = pairs[..k].iter().map(|&(_, ij)| ij).collect::<Vec<_>>();
+        let expected = component_sizes_naive(points.len(), &edges);
+        let sets = connect_closest(&points, *num_connections);
+        let mut sizes = sets.set_sizes().iter().copied().filter(|&s| s != 0).collect::<Vec<_>>();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        prop_assert_eq!(sizes, expected);
+        prop_assert_eq!(largest_sets_product(&sets), expected.iter().take(3).product::<usize>());
+        Ok(())
+    });
+}
+
+#[test]
+fn final_connection_is_at_connecting_distance() {
+    check(points_strategy(), |points| {
+        let points = points.iter().map(|&(x, y, z)| [x, y, z]).collect::<Vec<_>>();
+        let pairs = sorted_pairs(&points);
+        // The shortest distance such that the pairs up to it connect all the points
+        let connecting = (0..pairs.len()).find(|&k| {
+            let edges = pairs[..=k].iter().map(|&(_, ij)| ij).collect::<Vec<_>>();
+            component_sizes_naive(points.len(), &edges).len() == 1
+        });
+        let result = final_connection(&points);
+        prop_assert_eq!(result.map(|(i, j)| sqr_distance(&points[i], &points[j])), connecting.map(|k| pairs[k].0));
+        Ok(())
+    });
+}
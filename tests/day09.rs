@@ -0,0 +1,83 @@
+#[macro_use]
+mod support;
+
+use std::collections::{BTreeMap, BTreeSet};
+use aoc_2025::day09::{largest_inner_rectangle, largest_inner_rectangle_naive};
+use support::{check, vec_of};
+
+const SIZE: i64 = 5;
+
+/// Grows a polyomino on a `SIZE`x`SIZE` board, skipping cells not adjacent to it so far.
+fn grow_shape(cells: &[u8]) -> BTreeSet<[i64; 2]> {
+    let cell_pos = |c: u8| [i64::from(c) % SIZE, i64::from(c) / SIZE];
+    let mut shape = BTreeSet::from([cell_pos(cells[0])]);
+    for &c in &cells[1..] {
+        let [x, y] = cell_pos(c);
+        if [[x - 1, y], [x + 1, y], [x, y - 1], [x, y + 1]].iter().any(|n| shape.contains(n)) {
+            shape.insert([x, y]);
+        }
+    }
+    shape
+}
+
+/// Whether the outline of `shape` is a single simple loop: no holes and no cells touching only at
+/// a corner.
+fn has_simple_outline(shape: &BTreeSet<[i64; 2]>) -> bool {
+    let filled = |x, y| shape.contains(&[x, y]);
+    for y in -1..SIZE {
+        for x in -1..SIZE {
+            if filled(x, y) == filled(x + 1, y + 1) && filled(x + 1, y) == filled(x, y + 1) && filled(x, y) != filled(x + 1, y) {
+                return false;
+            }
+        }
+    }
+
+    // Every empty cell has to be reachable from outside the board
+    let mut outside = BTreeSet::from([[-1, -1]]);
+    let mut stack = vec![[-1, -1]];
+    while let Some([x, y]) = stack.pop() {
+        for n @ [nx, ny] in [[x - 1, y], [x + 1, y], [x, y - 1], [x, y + 1]] {
+            if (-1..=SIZE).contains(&nx) && (-1..=SIZE).contains(&ny) && !filled(nx, ny) && outside.insert(n) {
+                stack.push(n);
+            }
+        }
+    }
+    outside.len() + shape.len() == (SIZE as usize + 2).pow(2)
+}
+
+/// Corners of the outline of `shape`, in clockwise order.
+fn outline(shape: &BTreeSet<[i64; 2]>, scale: u64) -> Vec<[u64; 2]> {
+    let filled = |x, y| shape.contains(&[x, y]);
+    // Edges between the shape and the outside, directed so the shape is on their right
+    let mut next = BTreeMap::new();
+    for &[x, y] in shape {
+        if !filled(x, y - 1) { next.insert([x, y], [x + 1, y]); }
+        if !filled(x + 1, y) { next.insert([x + 1, y], [x + 1, y + 1]); }
+        if !filled(x, y + 1) { next.insert([x + 1, y + 1], [x, y + 1]); }
+        if !filled(x - 1, y) { next.insert([x, y + 1], [x, y]); }
+    }
+
+    let start = *next.keys().next().unwrap();
+    let mut vertices = vec![start];
+    while next[vertices.last().unwrap()] != start {
+        vertices.push(next[vertices.last().unwrap()]);
+    }
+    let n = vertices.len();
+    let is_corner = |i: usize| {
+        let [a, b, c] = [vertices[(i + n - 1) % n], vertices[i], vertices[(i + 1) % n]];
+        (b[0] - a[0], b[1] - a[1]) != (c[0] - b[0], c[1] - b[1])
+    };
+    (0..n).filter(|&i| is_corner(i)).map(|i| vertices[i].map(|v| v as u64 * scale)).collect()
+}
+
+#[test]
+fn largest_inner_rectangle_matches_naive() {
+    // Scaled up as well, so that edges don't always run through neighbouring tiles
+    check((1..=3u64, vec_of(0..=24u8, 1..=16)), |(scale, cells)| {
+        let shape = grow_shape(cells);
+        prop_assume!(has_simple_outline(&shape));
+        let points = outline(&shape, *scale);
+        prop_assert_eq!(largest_inner_rectangle(&points), largest_inner_rectangle_naive(&points));
+        Ok(())
+    });
+}
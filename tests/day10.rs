@@ -0,0 +1,78 @@
+#[macro_use]
+mod support;
+
+use std::collections::VecDeque;
+use aoc_2025::cancel::Cancel;
+use aoc_2025::day10::{min_presses_joltage, min_presses_joltage_search, min_presses_lights, Machine};
+use support::{check, vec_of};
+
+const NUM_LIGHTS: u32 = 4;
+
+/// A machine with `buttons`, whose desired lights and joltages are reached by pressing each
+/// button the given number of times.
+fn machine(buttons: &[(u32, u32)]) -> Machine {
+    let joltages = (0..NUM_LIGHTS)
+        .map(|light| buttons.iter().filter(|(b, _)| b & 1 << light != 0).map(|(_, presses)| presses).sum())
+        .collect();
+    let desired_lights = buttons.iter().filter(|(_, presses)| presses % 2 == 1).fold(0, |lights, (b, _)| lights ^ b);
+    Machine { num_lights: NUM_LIGHTS, desired_lights, buttons: buttons.iter().map(|(b, _)| *b).collect(), joltages }
+}
+
+/// Fewest presses to turn on the desired lights, by breadth-first search over the light states.
+fn min_presses_lights_naive(machine: &Machine) -> u32 {
+    let mut presses = vec![None; 1 << machine.num_lights];
+    presses[0] = Some(0);
+    let mut queue = VecDeque::from([0u32]);
+    while let Some(lights) = queue.pop_front() {
+        for b in &machine.buttons {
+            let next = (lights ^ b) as usize;
+            if presses[next].is_none() {
+                presses[next] = Some(presses[lights as usize].unwrap() + 1);
+                queue.push_back(next as u32);
+            }
+        }
+    }
+    presses[machine.desired_lights as usize].unwrap()
+}
+
+/// Fewest presses to reach the joltages, trying every number of presses of every button up to
+/// the lowest joltage it affects.
+fn min_presses_joltage_naive(machine: &Machine) -> u32 {
+    fn search(machine: &Machine, button: usize, remaining: &mut [u32]) -> Option<u32> {
+        let Some(&b) = machine.buttons.get(button) else {
+            return remaining.iter().all(|&j| j == 0).then_some(0);
+        };
+        let lights = (0..remaining.len()).filter(|&l| b & 1 << l != 0).collect::<Vec<_>>();
+        let max_presses = lights.iter().map(|&l| remaining[l]).min().unwrap();
+        let mut best = None::<u32>;
+        for presses in 0..=max_presses {
+            lights.iter().for_each(|&l| remaining[l] -= presses);
+            if let Some(rest) = search(machine, button + 1, remaining) {
+                best = Some(best.map_or(presses + rest, |best| best.min(presses + rest)));
+            }
+            lights.iter().for_each(|&l| remaining[l] += presses);
+        }
+        best
+    }
+    search(machine, 0, &mut machine.joltages.clone()).unwrap()
+}
+
+#[test]
+fn light_presses_match_naive() {
+    check(vec_of((1..=15u32, 0..=3u32), 1..=6), |buttons| {
+        let machine = machine(buttons);
+        prop_assert_eq!(min_presses_lights(&machine), min_presses_lights_naive(&machine));
+        Ok(())
+    });
+}
+
+#[test]
+fn joltage_presses_match_naive() {
+    check(vec_of((1..=15u32, 0..=6u32), 1..=5), |buttons| {
+        let machine = machine(buttons);
+        let expected = min_presses_joltage_naive(&machine);
+        prop_assert_eq!(min_presses_joltage(&machine), expected);
+        prop_assert_eq!(min_presses_joltage_search(&machine, &Cancel::never()), Ok(expected));
+        Ok(())
+    });
+}
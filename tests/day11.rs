@@ -0,0 +1,66 @@
+#[macro_use]
+mod support;
+
+use std::time::Duration;
+use aoc_2025::cancel::Cancel;
+use aoc_2025::day11::{count_paths, count_paths_via_fft_dac, parse_connections, Connections, NodeId, PathError};
+use support::{check, vec_of};
+
+const NUM_NODES: u8 = 10;
+
+/// Node 0 is `svr` and the last node is `out`. `fft` and `dac` are two of the others.
+fn node_id(i: u8, fft: u8, dac: u8) -> NodeId {
+    match i {
+        0 => *b"svr",
+        _ if i == NUM_NODES - 1 => *b"out",
+        _ if i == fft => *b"fft",
+        _ if i == dac => *b"dac",
+        _ => [b'n', b'0', b'0' + i],
+    }
+}
+
+/// An acyclic graph with the `(from, to)` edges that go from a lower node to a higher one.
+fn connections(edges: &[(u8, u8)], fft: u8, dac: u8) -> Connections {
+    let mut connections = (0..NUM_NODES - 1).map(|i| (node_id(i, fft, dac), Vec::new())).collect::<Connections>();
+    for &(from, to) in edges.iter().filter(|(from, to)| from < to) {
+        connections.get_mut(&node_id(from, fft, dac)).unwrap().push(node_id(to, fft, dac));
+    }
+    connections
+}
+
+/// Every path from `node` to `out`, each as the nodes along it.
+fn all_paths(connections: &Connections, node: NodeId, path: &mut Vec<NodeId>, paths: &mut Vec<Vec<NodeId>>) {
+    path.push(node);
+    if &node == b"out" {
+        paths.push(path.clone());
+    } else {
+        for &next in &connections[&node] {
+            all_paths(connections, next, path, paths);
+        }
+    }
+    path.pop();
+}
+
+#[test]
+fn path_counts_match_enumeration() {
+    let edges = vec_of((0..=NUM_NODES - 1, 0..=NUM_NODES - 1), 0..=30);
+    check((edges, 1..=NUM_NODES - 2, 1..=NUM_NODES - 2), |(edges, fft, dac)| {
+        prop_assume!(fft != dac);
+        let connections = connections(edges, *fft, *dac);
+        let mut paths = Vec::new();
+        all_paths(&connections, *b"svr", &mut Vec::new(), &mut paths);
+
+        prop_assert_eq!(count_paths(&connections, b"svr", &Cancel::never()), Ok(paths.len() as u64));
+        let via_both = paths.iter().filter(|p| p.contains(b"fft") && p.contains(b"dac")).count();
+        prop_assert_eq!(count_paths_via_fft_dac(&connections, &Cancel::never()), Ok(via_both as u64));
+        Ok(())
+    });
+}
+
+#[test]
+fn cycles_are_errors() {
+    let connections = parse_connections(["svr: fft", "fft: dac", "dac: aaa out", "aaa: fft"]);
+    assert_eq!(count_paths(&connections, b"svr", &Cancel::never()), Err(PathError::Cycle(*b"fft")));
+    assert_eq!(count_paths_via_fft_dac(&connections, &Cancel::never()), Err(PathError::Cycle(*b"fft")));
+    assert!(count_paths_via_fft_dac(&connections, &Cancel::with_timeout(Duration::ZERO)).is_err_and(|e| matches!(e, PathError::Cancelled(_))));
+}
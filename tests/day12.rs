@@ -0,0 +1,41 @@
+#[macro_use]
+mod support;
+
+use aoc_2025::day12::parse_presents;
+use support::{check, vec_of};
+
+// There is no oracle for the answer itself: the solver only compares areas, which is exact for the
+// puzzle's inputs but not in general, and a brute-force packing is too slow for random regions of
+// any useful size. This checks the parsing and the area arithmetic the answer depends on.
+#[test]
+fn parsed_areas_match_text() {
+    // Shapes as 9-bit masks of their cells, regions as (width, height, count of each shape)
+    let shapes = vec_of(1..=511u32, 1..=4);
+    let regions = vec_of((1..=50u32, 1..=50u32, vec_of(0..=20u32, 4..=4)), 0..=5);
+    check((shapes, regions), |(shapes, regions)| {
+        let mut text = String::new();
+        for (i, mask) in shapes.iter().enumerate() {
+            text += &format!("{i}:\n");
+            for y in 0..3 {
+                text += &(0..3).map(|x| if mask & 1 << (y * 3 + x) != 0 { '#' } else { '.' }).collect::<String>();
+                text += "\n";
+            }
+            text += "\n";
+        }
+        for (w, h, counts) in regions {
+            let counts = counts[..shapes.len()].iter().map(u32::to_string).collect::<Vec<_>>().join(" ");
+            text += &format!("{w}x{h}: {counts}\n");
+        }
+
+        let presents = parse_presents(text.lines());
+        prop_assert_eq!(presents.shapes.iter().map(|s| s.area).collect::<Vec<_>>(), shapes.iter().map(|m| m.count_ones()).collect::<Vec<_>>());
+        prop_assert_eq!(presents.regions.len(), regions.len());
+        for (region, (w, h, counts)) in presents.regions.iter().zip(regions) {
+            let shape_area = shapes.iter().zip(counts).map(|(m, c)| m.count_ones() * c).sum::<u32>();
+            prop_assert_eq!(region.area(), w * h);
+            prop_assert_eq!(region.shape_area(&presents.shapes), shape_area);
+            prop_assert_eq!(region.fits_by_area(&presents.shapes), shape_area <= w * h);
+        }
+        Ok(())
+    });
+}
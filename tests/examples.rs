@@ -0,0 +1,60 @@
+mod support;
+
+use aoc_2025::examples::{self, parse_manifest, Example};
+use aoc_2025::extract::{examples_for, parse_page, update_manifest, PartExample};
+
+#[test]
+fn every_example_in_manifest_matches() {
+    let mut days = examples::load_manifest().unwrap().iter().filter_map(Example::day).collect::<Vec<_>>();
+    days.sort_unstable();
+    days.dedup();
+    for day in days {
+        support::check_examples(day);
+    }
+}
+
+const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 11: Reactor ---</h2><p>For <em>example</em>:</p>
+<pre><code>you: <em>out</em>
+</code></pre><p>Also shown step by step:</p><pre><code>ignored</code></pre>
+<p>There are <code><em>1</em></code> paths &amp; so on.</p>
+<p><em>How many different paths lead from <code>you</code> to <code>out</code>?</em></p></article>
+<p>Your puzzle answer was <code>5</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>a &lt;- b
+</code></pre><p>Total: <code>1 + 1</code> = <code><em>2</em></code>.</p><p><em>What is the total?</em></p></article>
+</main>"#;
+
+#[test]
+fn page_gives_first_block_and_last_emphasised_code_per_part() {
+    let parts = parse_page(PAGE);
+    assert_eq!(parts, [
+        PartExample { input: Some("you: out\n".to_string()), answer: Some("1".to_string()) },
+        PartExample { input: Some("a <- b\n".to_string()), answer: Some("2".to_string()) },
+    ]);
+
+    let (files, entries) = examples_for(11, &parts);
+    assert_eq!(files.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), ["day11.txt", "day11b.txt"]);
+    assert_eq!(entries[1], Example { file: "day11b.txt".to_string(), part: 2, answer: "2".to_string() });
+}
+
+#[test]
+fn part_without_block_reuses_previous_example() {
+    let parts = [
+        PartExample { input: Some("1 2\n".to_string()), answer: Some("3".to_string()) },
+        PartExample { input: None, answer: Some("4".to_string()) },
+    ];
+    let (files, entries) = examples_for(2, &parts);
+    assert_eq!(files.len(), 1);
+    assert_eq!(entries.iter().map(|e| e.file.as_str()).collect::<Vec<_>>(), ["day02.txt", "day02.txt"]);
+}
+
+#[test]
+fn manifest_update_replaces_day() {
+    let manifest = "# header\nday01.txt 1 3\n# day02.txt 1 <answer>\nday03.txt 2 9\n";
+    let entry = Example { file: "day02.txt".to_string(), part: 1, answer: "7".to_string() };
+    let updated = update_manifest(manifest, 2, std::slice::from_ref(&entry));
+    assert_eq!(updated, "# header\nday01.txt 1 3\nday03.txt 2 9\nday02.txt 1 7\n");
+    assert_eq!(update_manifest(&updated, 2, &[entry]), updated);
+    assert_eq!(parse_manifest(&updated).unwrap().len(), 3);
+}
@@ -0,0 +1,63 @@
+#[macro_use]
+mod support;
+
+use std::io;
+use aoc_2025::day07::{Manifold, Rules};
+use aoc_2025::day09::{largest_inner_rectangle, largest_inner_rectangle_naive};
+use aoc_2025::generate::{generate, Size, DAYS};
+use aoc_2025::runner;
+use support::check;
+
+#[test]
+fn same_seed_gives_same_input() {
+    let size = Size { records: Some(20), width: None };
+    for day in DAYS {
+        let input = generate(day, 1234, &size).unwrap();
+        assert!(!input.is_empty());
+        assert_eq!(input, generate(day, 1234, &size).unwrap(), "day {day}");
+        assert_ne!(input, generate(day, 4321, &size).unwrap(), "day {day}");
+    }
+}
+
+#[test]
+fn generated_manifolds_match_naive() {
+    check((0..=u64::MAX, 1..=30usize, 1..=15usize), |&(seed, height, width)| {
+        let input = generate(7, seed, &Size { records: Some(height), width: Some(width) }).unwrap();
+        let manifold = Manifold::parse(&input.lines().collect::<Vec<_>>());
+        let rules = Rules::default();
+        prop_assert_eq!(manifold.count_splits(&rules), manifold.count_splits_naive(&rules));
+        prop_assert_eq!(manifold.count_timelines(&rules), manifold.count_timelines_naive(&rules));
+        Ok(())
+    });
+}
+
+#[test]
+fn generated_loops_match_naive() {
+    check((0..=u64::MAX, 1..=6usize), |&(seed, columns)| {
+        let input = generate(9, seed, &Size { records: Some(columns), width: Some(30) }).unwrap();
+        let points = input.lines().map(|line| {
+            let (x, y) = line.split_once(',').unwrap();
+            [x.parse().unwrap(), y.parse().unwrap()]
+        }).collect::<Vec<[u64; 2]>>();
+        prop_assert_eq!(largest_inner_rectangle(&points), largest_inner_rectangle_naive(&points));
+        Ok(())
+    });
+}
+
+#[test]
+fn generated_inputs_solve_or_are_rejected() {
+    check((1..=12u32, 0..=u64::MAX, 0..=10usize, 0..=16usize), |&(day, seed, records, width)| {
+        let solver = runner::DAYS.iter().find(|d| d.day == day).unwrap();
+        let input = match generate(day, seed, &Size { records: Some(records), width: Some(width) }) {
+            Ok(input) => input,
+            Err(e) => {
+                prop_assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+                return Ok(());
+            }
+        };
+        let (answers, _) = solver.solve(&input);
+        prop_assert_eq!(answers.part1.is_ok(), true);
+        prop_assert_eq!(answers.part2.is_none_or(|part2| part2.is_ok()), true);
+        Ok(())
+    });
+}
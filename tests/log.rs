@@ -0,0 +1,28 @@
+use aoc_2025::log::{add_directives, enabled, set_level, Level};
+
+#[test]
+fn levels_parse_by_name() {
+    assert_eq!(Level::parse("TRACE"), Some(Level::Trace));
+    assert_eq!(Level::parse("warn"), Some(Level::Warn));
+    assert_eq!(Level::parse("verbose"), None);
+    assert_eq!(format!("[{:5}]", Level::Info), "[info ]");
+}
+
+#[test]
+fn target_directives_apply_to_prefix() {
+    assert!(!enabled("aoc_2025::logtest_a", Level::Debug));
+    set_level("logtest_a", Level::Debug);
+    assert!(enabled("aoc_2025::logtest_a", Level::Debug));
+    assert!(enabled("logtest_a_bin", Level::Debug));
+    assert!(!enabled("aoc_2025::logtest_a", Level::Trace));
+    assert!(!enabled("aoc_2025::logtest_b", Level::Debug));
+
+    // The more specific directive wins, whatever the order
+    assert_eq!(add_directives("logtest_c::inner=trace,logtest_c=info"), Some(()));
+    assert!(enabled("aoc_2025::logtest_c::inner", Level::Trace));
+    assert!(!enabled("aoc_2025::logtest_c", Level::Debug));
+    assert!(enabled("aoc_2025::logtest_c", Level::Info));
+
+    assert_eq!(add_directives("logtest_d=loud"), None);
+    assert!(!enabled("logtest_d", Level::Info));
+}
@@ -0,0 +1,36 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use aoc_2025::day12::Day12;
+use aoc_2025::generate::{generate, Size};
+use aoc_2025::progress::{self, describe, Progress, Update};
+use aoc_2025::solver::Solver;
+
+#[test]
+fn solver_reports_each_record_then_finishes() {
+    let presents = Day12::parse(&generate(12, 1, &Size { records: Some(7), width: None }).unwrap());
+    let updates = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&updates);
+    let answer = progress::install(move |update: &Update| sink.borrow_mut().push(*update), || Day12::part1(&presents));
+    assert!(answer.is_ok());
+
+    let updates = updates.borrow();
+    let done = updates.iter().map(|u| (u.done, u.finished)).collect::<Vec<_>>();
+    assert_eq!(done, [(1, false), (2, false), (3, false), (4, false), (5, false), (6, false), (7, false), (7, true)]);
+    assert!(updates.iter().all(|u| u.kind == "region" && u.total == 7));
+
+    // Nothing is sent once the sink is uninstalled
+    let mut progress = Progress::new("region", 1);
+    progress.advance();
+    drop(progress);
+    assert_eq!(updates.len(), 8);
+}
+
+#[test]
+fn eta_extrapolates_time_per_record() {
+    let update = Update { kind: "machine", done: 10, total: 40, elapsed: Duration::from_secs(2), finished: false };
+    assert_eq!(update.eta(), Some(Duration::from_secs(6)));
+    assert_eq!(Update { done: 0, ..update }.eta(), None);
+    assert_eq!(describe("day 10", &update, false), "day 10: machine 10/40 ETA 6.0s");
+    assert_eq!(describe("day 10", &update, true), format!("day 10: machine 10/40 [{}{}] ETA 6.0s", "#".repeat(7), " ".repeat(23)));
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+use aoc_2025::day03::Day03;
+use aoc_2025::generate::{generate, Size};
+use aoc_2025::runner::{input_hash, Spread, DAYS};
+use aoc_2025::solver::Solver;
+
+#[test]
+fn spread_takes_middle_of_sorted_samples() {
+    let ms = Duration::from_millis;
+    let odd = Spread::of([ms(5), ms(1), ms(3)]);
+    assert_eq!((odd.min, odd.median, odd.max), (ms(1), ms(3), ms(5)));
+    let even = Spread::of([ms(4), ms(1), ms(2), ms(8)]);
+    assert_eq!((even.min, even.median, even.max), (ms(1), ms(3), ms(8)));
+}
+
+#[test]
+fn every_day_solves_generated_input() {
+    for day in DAYS {
+        let input = generate(day.day, 1, &Size { records: Some(10), width: None }).unwrap();
+        let (answers, timings) = day.solve(&input);
+        assert!(!answers.part1.unwrap().is_empty(), "day {}", day.day);
+        assert_eq!(answers.part2.is_some(), timings.part2.is_some(), "day {}", day.day);
+        assert_eq!(answers.part2.is_none(), day.day == 12, "day {}", day.day);
+    }
+}
+
+#[test]
+fn panicking_parse_fails_both_parts() {
+    let day1 = DAYS.iter().find(|d| d.day == 1).unwrap();
+    let (answers, _) = day1.solve("X12\n");
+    assert!(answers.part1.unwrap_err().contains("Unexpected line prefix"));
+    assert!(answers.part2.unwrap().is_err());
+}
+
+#[test]
+fn input_hash_is_fnv1a() {
+    assert_eq!(input_hash(""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(input_hash("a"), 0xaf63_dc4c_8601_ec8c);
+}
+
+#[test]
+fn records_add_up_to_answer() {
+    let banks = Day03::parse(&generate(3, 1, &Size::default()).unwrap());
+    for (answer, records) in [(Day03::part1(&banks), Day03::part1_records(&banks)), (Day03::part2(&banks).unwrap(), Day03::part2_records(&banks))] {
+        let records = records.unwrap();
+        assert_eq!(records.len(), banks.banks.len());
+        assert_eq!(Ok(records.iter().map(|r| r.parse::<u64>().unwrap()).sum::<u64>().to_string()), answer);
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use aoc_2025::examples::{self, day_of, parse_manifest, Example};
+use aoc_2025::scaffold::{add_module, new_day, register_day};
+
+#[test]
+fn new_module_goes_in_order() {
+    let lib_rs = add_module(include_str!("../src/lib.rs"), 13).unwrap();
+    let modules = lib_rs.lines().filter(|line| line.starts_with("pub mod ")).collect::<Vec<_>>();
+    assert!(modules.is_sorted());
+    assert!(modules.contains(&"pub mod day13;"));
+}
+
+#[test]
+fn new_day_is_registered_with_runner() {
+    let runner_rs = register_day(include_str!("../src/runner.rs"), 13).unwrap();
+    assert!(runner_rs.contains(", day12, day13};"));
+    assert!(runner_rs.contains("    Day::new::<day12::Day12>(12),\n    Day::new::<day13::Day13>(13),\n];"));
+}
+
+#[test]
+fn manifest_files_name_their_day() {
+    assert_eq!(day_of("day07.txt"), Some(7));
+    assert_eq!(day_of("day11b.txt"), Some(11));
+    assert_eq!(day_of("notes.txt"), None);
+    let manifest = parse_manifest("# comment\nday11b.txt 2 2\n").unwrap();
+    assert_eq!(manifest, [Example { file: "day11b.txt".to_string(), part: 2, answer: "2".to_string() }]);
+    assert_eq!(manifest[0].path(), std::path::Path::new(examples::DIR).join("day11b.txt"));
+    for bad in ["day01.txt one 3", "day01.txt 1", "notes.txt 1 3"] {
+        assert_eq!(parse_manifest(&format!("# comment\n{bad}\n")).unwrap_err().kind(), io::ErrorKind::InvalidData, "{bad}");
+    }
+}
+
+/// A copy of the files `new_day` changes, in a fresh directory.
+fn scratch_repo(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::copy("src/lib.rs", root.join("src/lib.rs")).unwrap();
+    fs::copy("src/runner.rs", root.join("src/runner.rs")).unwrap();
+    root
+}
+
+fn file_names(dir: &Path) -> Vec<String> {
+    let mut names = fs::read_dir(dir).unwrap().map(|e| e.unwrap().file_name().into_string().unwrap()).collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[test]
+fn new_day_creates_and_registers_files() {
+    let root = scratch_repo("ok");
+    let paths = new_day(&root, 13).unwrap();
+    assert_eq!(paths.len(), 7);
+    assert!(paths.iter().all(|path| path.exists()));
+    assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("pub mod day13;"));
+    assert_eq!(new_day(&root, 13).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn failed_new_day_leaves_no_files_behind() {
+    // Registering fails before anything is written
+    let root = scratch_repo("unregistrable");
+    fs::write(root.join("src/runner.rs"), "// No DAYS table\n").unwrap();
+    assert_eq!(new_day(&root, 13).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(file_names(&root), ["src"]);
+    assert_eq!(file_names(&root.join("src")), ["lib.rs", "runner.rs"]);
+    fs::remove_dir_all(root).unwrap();
+
+    // Writing fails partway, at the input, since a file is in the way of its directory
+    let root = scratch_repo("unwritable");
+    let lib_rs = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    fs::write(root.join("local"), "").unwrap();
+    assert!(new_day(&root, 13).is_err());
+    assert_eq!(file_names(&root), ["local", "src"]);
+    assert_eq!(file_names(&root.join("src")), ["lib.rs", "runner.rs"]);
+    assert_eq!(fs::read_to_string(root.join("src/lib.rs")).unwrap(), lib_rs);
+    assert!(!root.join(examples::MANIFEST).exists());
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,202 @@
+//! Minimal property-based testing: random inputs from a seeded generator, with failing inputs
+//! greedily shrunk to a minimal case.
+//!
+//! `PROP_CASES` and `PROP_SEED` override the number of cases and the seed.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+pub use aoc_2025::rng::Rng;
+use aoc_2025::examples;
+use aoc_2025::runner::find_day;
+
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Simpler variations of `value`, most aggressive first.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+macro_rules! impl_int_strategy {
+    ($($t:ty),*) => {$(
+        impl Strategy for RangeInclusive<$t> {
+            type Value = $t;
+
+            fn generate(&self, rng: &mut Rng) -> $t {
+                let span = (*self.end() as i128 - *self.start() as i128 + 1) as u64;
+                (*self.start() as i128 + rng.below(span) as i128) as $t
+            }
+
+            fn shrink(&self, &value: &$t) -> Vec<$t> {
+                // Towards zero if possible, otherwise towards the closest bound
+                let target = (0 as $t).clamp(*self.start(), *self.end());
+                if value == target {
+                    return Vec::new();
+                }
+                let midpoint = ((value as i128 + target as i128) / 2) as $t;
+                let step = if value > target { value - 1 } else { value + 1 };
+                let mut candidates = vec![target, midpoint, step];
+                candidates.dedup();
+                candidates.retain(|&c| c != value);
+                candidates
+            }
+        }
+    )*};
+}
+
+impl_int_strategy!(u8, u32, u64, usize, i64);
+
+#[derive(Clone)]
+pub struct VecOf<S> {
+    element: S,
+    len: RangeInclusive<usize>,
+}
+
+pub fn vec_of<S: Strategy>(element: S, len: RangeInclusive<usize>) -> VecOf<S> {
+    VecOf { element, len }
+}
+
+impl<S: Strategy> Strategy for VecOf<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = self.len.generate(rng);
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = Vec::new();
+        let min_len = *self.len.start();
+        if value.len() > min_len {
+            candidates.push(value[..min_len].to_vec());
+            let half = value.len() / 2;
+            if half >= min_len && half < value.len() {
+                candidates.push(value[..half].to_vec());
+                candidates.push(value[value.len() - half..].to_vec());
+            }
+            for i in 0..value.len() {
+                let mut smaller = value.clone();
+                smaller.remove(i);
+                candidates.push(smaller);
+            }
+        }
+        for (i, element) in value.iter().enumerate() {
+            for shrunk in self.element.shrink(element) {
+                let mut simpler = value.clone();
+                simpler[i] = shrunk;
+                candidates.push(simpler);
+            }
+        }
+        candidates
+    }
+}
+
+macro_rules! impl_tuple_strategy {
+    ($(($s:ident, $i:tt)),*) => {
+        impl<$($s: Strategy),*> Strategy for ($($s,)*) {
+            type Value = ($($s::Value,)*);
+
+            fn generate(&self, rng: &mut Rng) -> Self::Value {
+                ($(self.$i.generate(rng),)*)
+            }
+
+            fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                let mut candidates = Vec::new();
+                $(
+                    for shrunk in self.$i.shrink(&value.$i) {
+                        let mut simpler = value.clone();
+                        simpler.$i = shrunk;
+                        candidates.push(simpler);
+                    }
+                )*
+                candidates
+            }
+        }
+    };
+}
+
+impl_tuple_strategy!((A, 0), (B, 1));
+impl_tuple_strategy!((A, 0), (B, 1), (C, 2));
+impl_tuple_strategy!((A, 0), (B, 1), (C, 2), (D, 3));
+
+/// Result of checking a property on one input. `Err` holds a description of the failure.
+pub type PropResult = Result<(), String>;
+
+/// Fails the property if the two values differ.
+#[allow(unused_macros)]
+macro_rules! prop_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if left != right {
+            return Err(format!("{} != {}\n  left: {:?}\n right: {:?}", stringify!($left), stringify!($right), left, right));
+        }
+    }};
+}
+
+/// Skips inputs that don't meet the property's preconditions.
+#[allow(unused_macros)]
+macro_rules! prop_assume {
+    ($cond:expr) => {
+        if !$cond {
+            return Ok(());
+        }
+    };
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name).ok().and_then(|s| s.parse().ok()).unwrap_or(default)
+}
+
+/// Checks `property` against random inputs from `strategy`, panicking with a shrunk input if it
+/// fails.
+pub fn check<S: Strategy>(strategy: S, property: impl Fn(&S::Value) -> PropResult) {
+    const MAX_SHRINK_STEPS: usize = 10_000;
+
+    let cases = env_or("PROP_CASES", 256);
+    let seed = env_or("PROP_SEED", 0x5eed_2025);
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let value = strategy.generate(&mut rng);
+        let Err(mut error) = property(&value) else { continue };
+
+        let mut minimal = value;
+        let mut steps = 0;
+        'shrinking: while steps < MAX_SHRINK_STEPS {
+            for candidate in strategy.shrink(&minimal) {
+                steps += 1;
+                if let Err(e) = property(&candidate) {
+                    minimal = candidate;
+                    error = e;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        panic!("Property failed on case {case} (PROP_SEED={seed}), shrunk in {steps} steps to:\n{minimal:#?}\n{error}");
+    }
+}
+
+/// Checks a day's solver against every example answer for it in the manifest. Answers that are
+/// still commented out are skipped, but there must be at least one that isn't.
+pub fn check_examples(day: u32) {
+    let solver = find_day(day).unwrap_or_else(|| panic!("Day {day} isn't registered"));
+    let manifest = examples::load_manifest().unwrap();
+    let examples = manifest.iter().filter(|e| e.day() == Some(day)).collect::<Vec<_>>();
+    assert!(!examples.is_empty(), "No example answers for day {day} in {}", examples::MANIFEST);
+    for example in examples {
+        let input = std::fs::read_to_string(example.path()).unwrap();
+        let (answers, _) = solver.solve(&input);
+        let answer = match example.part {
+            1 => answers.part1,
+            2 => answers.part2.expect("Day has no second part"),
+            part => panic!("No part {part}"),
+        };
+        assert_eq!(answer.as_deref(), Ok(example.answer.as_str()), "{} part {}", example.file, example.part);
+    }
+}